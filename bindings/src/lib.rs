@@ -0,0 +1,8 @@
+//! WebAssembly and C bindings of `sp1-sui`, built as a shared library. The bindings live in the
+//! `wasm` and `ffi` modules of `sp1-sui`, which only builds an rlib so that Rust users do not
+//! compile a `cdylib`; this crate links them into the library loaded by JavaScript or C.
+
+#[cfg(feature = "ffi")]
+pub use sp1_sui::ffi::*;
+#[cfg(feature = "wasm")]
+pub use sp1_sui::wasm::*;
@@ -3,6 +3,7 @@ module groth16_verifier::groth16_verifier;
 
 use sui::groth16::{
     prepare_verifying_key,
+    pvk_from_bytes,
     proof_points_from_bytes,
     public_proof_inputs_from_bytes,
     bn254,
@@ -20,3 +21,20 @@ public fun verify_groth16_bn254_proof(
 
     assert!(verify_groth16_proof(&bn254(), &pvk, &public_inputs, &proof_points));
 }
+
+/// Verify a proof against a verifying key prepared off-chain with
+/// `sp1_sui::SuiPreparedVerifyingKey`, skipping `prepare_verifying_key`.
+public fun verify_groth16_bn254_proof_with_pvk(
+    vk_gamma_abc_g1: vector<u8>,
+    alpha_g1_beta_g2: vector<u8>,
+    gamma_g2_neg_pc: vector<u8>,
+    delta_g2_neg_pc: vector<u8>,
+    public_inputs: vector<u8>,
+    proof: vector<u8>,
+) {
+    let pvk = pvk_from_bytes(vk_gamma_abc_g1, alpha_g1_beta_g2, gamma_g2_neg_pc, delta_g2_neg_pc);
+    let public_inputs = public_proof_inputs_from_bytes(public_inputs);
+    let proof_points = proof_points_from_bytes(proof);
+
+    assert!(verify_groth16_proof(&bn254(), &pvk, &public_inputs, &proof_points));
+}
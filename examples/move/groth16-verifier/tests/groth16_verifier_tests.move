@@ -18,3 +18,15 @@ fun groth16_bn254_test() {
 
     assert!(groth16::verify_groth16_proof(&groth16::bn254(), &pvk, &public_inputs, &proof_points));
 }
+
+#[test]
+fun groth16_bn254_prepared_vk_test() {
+    groth16_verifier::groth16_verifier::verify_groth16_bn254_proof_with_pvk(
+        x"f70e83568364e74b565859af8ad37675b6a0e69ce4bb2dae6232353fc1e0d68ee3c932f53a1c69233c846c0d7fd938d6fb6ec6fa7f0c25b6ff286f698f1b619b8d7ba84ddfe70385801e86daa3f00fd58ceee6cf3711207b26916cde6b087429",
+        x"0d14dc30b678357d988b3eb0e8ada11bc7b2b5d2cf0c1fe27522cb2a819b7c044a601bd9302a94a80677a9f72ebeaada131e9bfba30621c8f038b547beb9962e182089742c1d388436771390c6af9937729c39e6414746ee5636c4741d1f220df45c80a7cded5ad653bc4f8b201c94054918dee160e1dc90cea027d4ec69e01a6df0f8d739d7911aa63b6b6923f10cdce763de1046fe0f91d590f5f510397611c57ac6daac2f9222d8cc3130e57f99dcd2edecb3e1d11b860c0d9d64a5dda30f42b8a8e513c9d5983486332c3ecd1236192b988666c15818838559bc27b6a50f49fee88fd43ac88dc3e75419bfd451374e25b8c4845b4bfcbd460ad48bb55016e4e1edf293696a43b76c8a5feffe6cfddb59cd7bc0246c1784061ed3eff2280a74e56fcabc5b93d84d72e10a0ad78079b02d06cc8c3ae435936ce2e85722d82b9480a46f039988a80cf4ca669c026100f8ce3fbc5298180dec08d2055d7a621ad3cc4fdd99242a86d7c80528d3b438c4669288c56b77abb8367528a31f01c511",
+        x"edf692d95cbdde46ddda5ef7d422436779445c5e66006a42761e1f12efde0018c212f3aeb785e49712e7a9353349aaf1255dfb31b7bf60723a480d9293938e99",
+        x"548e0e8380bc6b903abdb16eecd9e1e54fc80345a6d34055eff30526aec9652b1392906effd00cb94f0efb0303d84eaa08e7425dd7cb34308eaa1115e8ab2ea6",
+        x"81ff905c7241c6e4898b8e7b274fa55cf7f84980b917ec716e43a0b945356400909d7ee3f60d93829773af47c97bd2eaf35dec0b74c334799ce431cfdeb71c0f",
+        x"51019a2d675b0d546ef01cf1a9cfba45c2ca2a5ab6f007cb60808a9a89857802cd47ab1aa44bd6d997b9c1ab2263acfb386bfb3bafa10d31073b997f3a89e913208be8e1fbbece6432256eb71dd7aba26cd3bea56dde16b8fbf7563c2108fc0a90dd7ec7f83f0fe40b993c460f5c7468f8faebb0850925a55d59a07f3ff0011d",
+    );
+}
@@ -9,11 +9,11 @@ use sui_sdk::{
         base_types::ObjectID,
         programmable_transaction_builder::ProgrammableTransactionBuilder,
         quorum_driver_types::ExecuteTransactionRequestType,
-        transaction::{Argument, Command, Transaction, TransactionData},
+        transaction::{Argument, CallArg, Command, Transaction, TransactionData},
     },
 };
 
-use sp1_sui_sdk::utils::{serialize_input, setup_for_write};
+use sp1_sui_sdk::utils::setup_for_write;
 use sp1_sdk::SP1ProofWithPublicValues;
 use sp1_sui::convert_sp1_gnark_to_ark;
 
@@ -35,13 +35,13 @@ async fn main() -> Result<(), anyhow::Error> {
 
     // Load the SP1 zero-knowledge proof from file and convert it to Arkworks format
     let sp1_proof_with_public_values = SP1ProofWithPublicValues::load("../../proofs/fibonacci_proof.bin").unwrap();
-    let (pvk, public_inputs, proof_points) =
-        convert_sp1_gnark_to_ark(sp1_proof_with_public_values);
+    let artifacts = convert_sp1_gnark_to_ark(sp1_proof_with_public_values);
 
     // Add the proof components as inputs to the transaction
-    ptb.input(serialize_input(&pvk))?;           // Input 0: Verification key
-    ptb.input(serialize_input(&public_inputs))?;  // Input 1: Public inputs
-    ptb.input(serialize_input(&proof_points))?;   // Input 2: Proof points
+    let [pvk, public_inputs, proof_points] = artifacts.pure_args();
+    ptb.input(CallArg::Pure(pvk))?;           // Input 0: Verification key
+    ptb.input(CallArg::Pure(public_inputs))?;  // Input 1: Public inputs
+    ptb.input(CallArg::Pure(proof_points))?;   // Input 2: Proof points
 
     // Package and function defined in `examples/move/groth16-verifier`
     let package = ObjectID::from_hex_literal(&PKG_ID).map_err(|e| anyhow!(e))?;
@@ -0,0 +1,202 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+use sha2::{Digest, Sha256};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    generate_sp1_versions();
+
+    #[cfg(feature = "ffi")]
+    generate_ffi_header();
+}
+
+/// Generate the `Sp1Version` enum, the Groth16 verifying key registry and the
+/// `GROTH16_VK_<VERSION>_BYTES` constants from `vk/<version>/groth16_vk.bin`.
+fn generate_sp1_versions() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let vk_dir = Path::new(&crate_dir).join("vk");
+    println!("cargo:rerun-if-changed={}", vk_dir.display());
+
+    let mut versions = Vec::new();
+    for entry in fs::read_dir(&vk_dir).expect("Failed to read the vk directory") {
+        let path = entry.unwrap().path().join("groth16_vk.bin");
+        if !path.is_file() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", path.display());
+        let version = path
+            .parent()
+            .and_then(|dir| dir.file_name())
+            .and_then(|name| name.to_str())
+            .expect("SP1 version directories must be valid UTF-8")
+            .to_string();
+        let groth16_vk = fs::read(&path).unwrap();
+        versions.push((version, path, groth16_vk));
+    }
+    // Newest version first, with releases before their release candidates.
+    versions.sort_by_cached_key(|(version, _, _)| std::cmp::Reverse(version_key(version)));
+
+    let variants: Vec<String> = versions
+        .iter()
+        .map(|(version, _, _)| variant_name(version))
+        .collect();
+    for (i, variant) in variants.iter().enumerate() {
+        assert!(
+            !variants[..i].contains(variant),
+            "SP1 versions {} map to the same variant {variant}",
+            versions[i].0
+        );
+    }
+
+    let mut code = String::from("// Generated by build.rs from the `vk` directory.\n\n");
+    code += "/// An SP1 version with an embedded Groth16 verifying key.\n";
+    code += "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\npub enum Sp1Version {\n";
+    for ((version, _, _), variant) in versions.iter().zip(&variants) {
+        writeln!(code, "    /// SP1 `{version}`.\n    {variant},").unwrap();
+    }
+    code += "}\n\n";
+
+    code += "/// Every SP1 version with an embedded Groth16 verifying key, newest first.\n";
+    code += "const SUPPORTED_VERSIONS: &[Sp1Version] = &[\n";
+    for variant in &variants {
+        writeln!(code, "    Sp1Version::{variant},").unwrap();
+    }
+    code += "];\n\n";
+
+    code += "/// The embedded Groth16 verifying keys, newest first: the SP1 version, the gnark verifying\n";
+    code += "/// key bytes and the 4 byte prefix of the proofs made with the key.\n";
+    code += "pub const GROTH16_VK_REGISTRY: &[(Sp1Version, &[u8], [u8; 4])] = &[\n";
+    for variant in &variants {
+        writeln!(
+            code,
+            "    (Sp1Version::{variant}, Sp1Version::{variant}.groth16_vk(), Sp1Version::{variant}.groth16_vk_hash_prefix()),"
+        )
+        .unwrap();
+    }
+    code += "];\n\n";
+
+    code += "impl Sp1Version {\n";
+    code += "    /// The version name, as in the `vk` directory.\n";
+    code += "    pub const fn as_str(self) -> &'static str {\n        match self {\n";
+    for ((version, _, _), variant) in versions.iter().zip(&variants) {
+        writeln!(code, "            Self::{variant} => {version:?},").unwrap();
+    }
+    code += "        }\n    }\n\n";
+    code += "    /// The gnark Groth16 verifying key bytes.\n";
+    code += "    pub const fn groth16_vk(self) -> &'static [u8] {\n        match self {\n";
+    for ((_, path, _), variant) in versions.iter().zip(&variants) {
+        writeln!(
+            code,
+            "            Self::{variant} => include_bytes!({path:?}),"
+        )
+        .unwrap();
+    }
+    code += "        }\n    }\n\n";
+    code += "    /// The 4 byte prefix of the proofs made with the Groth16 verifying key.\n";
+    code += "    pub const fn groth16_vk_hash_prefix(self) -> [u8; 4] {\n        match self {\n";
+    for ((_, _, groth16_vk), variant) in versions.iter().zip(&variants) {
+        let hash = Sha256::digest(groth16_vk);
+        writeln!(code, "            Self::{variant} => {:?},", &hash[..4]).unwrap();
+    }
+    code += "        }\n    }\n}\n";
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("sp1_versions.rs"), code).unwrap();
+
+    let mut code = String::from("// Generated by build.rs from the `vk` directory.\n");
+    for ((version, _, _), variant) in versions.iter().zip(&variants) {
+        writeln!(
+            code,
+            "\n/// Groth16 verifying key of SP1 `{version}`.\npub const {}: &[u8] = Sp1Version::{variant}.groth16_vk();",
+            const_name(version)
+        )
+        .unwrap();
+    }
+    fs::write(Path::new(&out_dir).join("groth16_vks.rs"), code).unwrap();
+}
+
+/// Sort key of a version name such as `v4.0.0-rc.3`: the numeric release, then whether it is a
+/// final release, then the pre-release tag.
+fn version_key(version: &str) -> (Vec<u64>, bool, String) {
+    let version = version.trim_start_matches('v');
+    let end = version
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(version.len());
+    let (release, pre_release) = version.split_at(end);
+    let release = release
+        .split('.')
+        .filter_map(|part| part.parse().ok())
+        .collect();
+    let pre_release = pre_release.trim_start_matches('-');
+    (release, pre_release.is_empty(), pre_release.to_string())
+}
+
+/// Enum variant of a version name, e.g. `V4_0_0Rc3` for `v4.0.0-rc.3`.
+///
+/// Letters are capitalized per word, and consecutive numbers are separated by an underscore.
+fn variant_name(version: &str) -> String {
+    let mut name = String::new();
+    for word in version_words(version) {
+        let first = word.chars().next().unwrap();
+        if first.is_ascii_digit() && name.ends_with(|c: char| c.is_ascii_digit()) {
+            name.push('_');
+        }
+        name.push(first.to_ascii_uppercase());
+        name.push_str(&word[1..]);
+    }
+    name
+}
+
+/// Name of the verifying key constant of a version, such as `GROTH16_VK_4_0_0_RC3_BYTES` for
+/// `v4.0.0-rc.3`.
+fn const_name(version: &str) -> String {
+    let mut name = String::from("GROTH16_VK");
+    let mut previous_alphabetic = false;
+    for word in version_words(version.strip_prefix('v').unwrap_or(version)) {
+        let alphabetic = word.starts_with(|c: char| c.is_ascii_alphabetic());
+        if alphabetic || !previous_alphabetic {
+            name.push('_');
+        }
+        name.push_str(&word.to_ascii_uppercase());
+        previous_alphabetic = alphabetic;
+    }
+    name + "_BYTES"
+}
+
+/// Split a version name into alphanumeric words, also splitting between letters and digits.
+fn version_words(version: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for c in version.chars() {
+        let split = match word.chars().last() {
+            Some(last) => !c.is_ascii_alphanumeric() || last.is_ascii_digit() != c.is_ascii_digit(),
+            None => false,
+        };
+        if split {
+            words.push(std::mem::take(&mut word));
+        }
+        if c.is_ascii_alphanumeric() {
+            word.push(c);
+        }
+    }
+    words.push(word);
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+/// Generate the C header of the `ffi` module in `OUT_DIR`, where the tests check that the
+/// committed `include/sp1_sui.h` is up to date.
+#[cfg(feature = "ffi")]
+fn generate_ffi_header() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    cbindgen::generate(&crate_dir)
+        .expect("Failed to generate the C header")
+        .write_to_file(Path::new(&out_dir).join("sp1_sui.h"));
+}
@@ -0,0 +1,125 @@
+#ifndef SP1_SUI_H
+#define SP1_SUI_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Status codes returned by the C API.
+typedef enum Sp1SuiStatus {
+  SP1_SUI_STATUS_OK = 0,
+  // A pointer argument is null or a string is not valid UTF-8.
+  SP1_SUI_STATUS_INVALID_ARGUMENT = 1,
+  // The SP1 proof file could not be read.
+  SP1_SUI_STATUS_INVALID_PROOF_FILE = 2,
+  SP1_SUI_STATUS_NOT_GROTH16_PROOF = 3,
+  SP1_SUI_STATUS_INVALID_VKEY_HASH = 4,
+  SP1_SUI_STATUS_INVALID_COMMITTED_VALUES_DIGEST = 5,
+  SP1_SUI_STATUS_PUBLIC_VALUES_MISMATCH = 6,
+  SP1_SUI_STATUS_INVALID_PROOF = 7,
+  SP1_SUI_STATUS_INVALID_VERIFYING_KEY = 8,
+  SP1_SUI_STATUS_UNSUPPORTED_SP1_VERSION = 9,
+  SP1_SUI_STATUS_VERIFYING_KEY_MISMATCH = 10,
+  SP1_SUI_STATUS_VERIFICATION_FAILED = 11,
+  SP1_SUI_STATUS_SERIALIZATION_ERROR = 12,
+  // A Groth16 verifying key file could not be read.
+  SP1_SUI_STATUS_VERIFYING_KEY_IO_ERROR = 13,
+  SP1_SUI_STATUS_INVALID_PUBLIC_INPUT = 14,
+  SP1_SUI_STATUS_PUBLIC_INPUT_COUNT_MISMATCH = 15,
+  SP1_SUI_STATUS_UNSUPPORTED_COMMITMENTS = 16,
+  SP1_SUI_STATUS_INVALID_COMMITMENT = 17,
+  SP1_SUI_STATUS_INVALID_PUBLIC_WITNESS = 18,
+  // A gnark or snarkjs verifying key, proof or public input file could not be read.
+  SP1_SUI_STATUS_IO_ERROR = 19,
+  // A snarkjs verifying key, proof or public signals file is malformed.
+  SP1_SUI_STATUS_INVALID_SNARKJS = 20,
+  // A proof passed as a mock proof has proof data.
+  SP1_SUI_STATUS_NOT_MOCK_PROOF = 21,
+  // A mock proof was passed to a converter of real proofs, see [`sp1_sui_convert_mock`].
+  SP1_SUI_STATUS_MOCK_PROOF = 22,
+} Sp1SuiStatus;
+
+// A byte buffer owned by the library.
+typedef struct Sp1SuiBuffer {
+  uint8_t *data;
+  size_t len;
+} Sp1SuiBuffer;
+
+// The arguments of the `verify_groth16_bn254_proof` Move function.
+typedef struct Sp1SuiArtifacts {
+  // Compressed arkworks Groth16 verifying key.
+  struct Sp1SuiBuffer verifying_key;
+  // Concatenated 32 byte little endian public inputs.
+  struct Sp1SuiBuffer public_inputs;
+  // Compressed arkworks Groth16 proof points.
+  struct Sp1SuiBuffer proof_points;
+} Sp1SuiArtifacts;
+
+// Convert SP1 Groth16 proof bytes, as returned by `SP1ProofWithPublicValues::bytes`, to the
+// arguments of the Sui Groth16 verifier.
+//
+// The Groth16 verifying key is selected from the embedded keys using the prefix of the proof.
+// `vkey_hash` is the program vkey hash as returned by `SP1VerifyingKey::bytes32`. On success,
+// the artifacts are written to `out` and must be released with [`sp1_sui_artifacts_free`].
+// Proofs of the mock prover are rejected with [`Sp1SuiStatus::MockProof`]; convert them
+// with [`sp1_sui_convert_mock`].
+//
+// # Safety
+//
+// `proof_bytes` and `public_values` must point to `proof_len` and `public_values_len` readable
+// bytes, `vkey_hash` must be a nul terminated string and `out` must be valid for writes.
+enum Sp1SuiStatus sp1_sui_convert(const uint8_t *proof_bytes,
+                                  size_t proof_len,
+                                  const uint8_t *public_values,
+                                  size_t public_values_len,
+                                  const char *vkey_hash,
+                                  struct Sp1SuiArtifacts *out);
+
+// Convert the proof bytes of the SP1 mock prover to mock artifacts, which carry the public inputs
+// but an empty verifying key and empty proof points.
+//
+// Returns [`Sp1SuiStatus::NotMockProof`] if the proof bytes are not a mock proof. On success,
+// the artifacts are written to `out` and must be released with [`sp1_sui_artifacts_free`].
+//
+// # Safety
+//
+// See [`sp1_sui_convert`].
+enum Sp1SuiStatus sp1_sui_convert_mock(const uint8_t *proof_bytes,
+                                       size_t proof_len,
+                                       const uint8_t *public_values,
+                                       size_t public_values_len,
+                                       const char *vkey_hash,
+                                       struct Sp1SuiArtifacts *out);
+
+#if defined(SP1_SUI_SP1_SDK)
+// Convert a serialized `SP1ProofWithPublicValues` file to the arguments of the Sui Groth16
+// verifier. Requires the `sp1-sdk` feature.
+//
+// On success, the artifacts are written to `out` and must be released with
+// [`sp1_sui_artifacts_free`].
+//
+// # Safety
+//
+// `proof_path` must be a nul terminated string and `out` must be valid for writes.
+enum Sp1SuiStatus sp1_sui_convert_proof_file(const char *proof_path, struct Sp1SuiArtifacts *out);
+#endif
+
+// Release the buffers of artifacts returned by the converter.
+//
+// The buffers are reset to null, so releasing the same artifacts twice is harmless.
+//
+// # Safety
+//
+// `artifacts` must be null or point to artifacts written by the converter.
+void sp1_sui_artifacts_free(struct Sp1SuiArtifacts *artifacts);
+
+// The message of the last error on the calling thread, or null if the last call succeeded.
+//
+// The string is owned by the library and valid until the next call on the same thread.
+const char *sp1_sui_last_error_message(void);
+
+#endif  /* SP1_SUI_H */
@@ -1,10 +1,13 @@
-use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+use ark_bn254::{Bn254, Fq, Fr, G1Affine, G2Affine};
 use ark_ec::AffineRepr;
-use ark_ff::PrimeField;
+use ark_ff::{BigInteger, PrimeField};
 use ark_groth16::{Proof, VerifyingKey};
-use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
 use thiserror::Error;
 
+use crate::curve::GnarkCurve;
+use crate::gnark::GnarkGroth16VerifyingKey;
+
 pub const GNARK_MASK: u8 = 0b11 << 6;
 pub const GNARK_COMPRESSED_POSITIVE: u8 = 0b10 << 6;
 pub const GNARK_COMPRESSED_NEGATIVE: u8 = 0b11 << 6;
@@ -23,6 +26,34 @@ pub enum ArkGroth16Error {
     G2CompressionError,
     #[error("Invalid input")]
     InvalidInput,
+    #[error("Non-canonical field element encoding")]
+    NonCanonicalEncoding,
+    #[error("Point is not in the prime order subgroup")]
+    NotInSubgroup,
+    #[error("{field} at offset {offset}: {reason}")]
+    VerifyingKeyParse {
+        field: String,
+        offset: usize,
+        reason: &'static str,
+    },
+}
+
+/// How thoroughly curve points are validated when they are decoded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Reject non-canonical field encodings and run full curve and subgroup checks.
+    Strict,
+    /// Only run the checks decoding requires, for trusted inputs such as the embedded keys.
+    #[default]
+    Lenient,
+}
+
+/// Check that every 32 byte big endian chunk is a canonical base field element, i.e. `x < p`.
+pub(crate) fn is_canonical_fq(be_bytes: &[u8]) -> bool {
+    let modulus = Fq::MODULUS.to_bytes_be();
+    be_bytes
+        .chunks_exact(32)
+        .all(|chunk| chunk < modulus.as_slice())
 }
 
 /// Convert the endianness of a byte array, chunk by chunk.
@@ -75,6 +106,46 @@ pub fn decompress_g2(g2_bytes: &[u8; 64]) -> Result<G2Affine, ArkGroth16Error> {
     Ok(decompressed_g2)
 }
 
+/// Decompress a G1 point, validating it according to `mode`.
+pub fn decompress_g1_with_mode(
+    g1_bytes: &[u8; 32],
+    mode: ValidationMode,
+) -> Result<G1Affine, ArkGroth16Error> {
+    if mode == ValidationMode::Lenient {
+        return decompress_g1(g1_bytes);
+    }
+    let mut x = *g1_bytes;
+    x[0] &= !GNARK_MASK;
+    if !is_canonical_fq(&x) {
+        return Err(ArkGroth16Error::NonCanonicalEncoding);
+    }
+    let g1 = decompress_g1(g1_bytes)?;
+    if !g1.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ArkGroth16Error::NotInSubgroup);
+    }
+    Ok(g1)
+}
+
+/// Decompress a G2 point, validating it according to `mode`.
+pub fn decompress_g2_with_mode(
+    g2_bytes: &[u8; 64],
+    mode: ValidationMode,
+) -> Result<G2Affine, ArkGroth16Error> {
+    if mode == ValidationMode::Lenient {
+        return decompress_g2(g2_bytes);
+    }
+    let mut x = *g2_bytes;
+    x[0] &= !GNARK_MASK;
+    if !is_canonical_fq(&x) {
+        return Err(ArkGroth16Error::NonCanonicalEncoding);
+    }
+    let g2 = decompress_g2(g2_bytes)?;
+    if !g2.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(ArkGroth16Error::NotInSubgroup);
+    }
+    Ok(g2)
+}
+
 #[allow(dead_code)]
 pub fn gnark_flag_to_ark_flag(msb: u8) -> Result<u8, ArkGroth16Error> {
     let gnark_flag = msb & GNARK_MASK;
@@ -91,6 +162,45 @@ pub fn gnark_flag_to_ark_flag(msb: u8) -> Result<u8, ArkGroth16Error> {
     Ok(msb & !ARK_MASK | ark_flag)
 }
 
+/// Compress a G1 point in the gnark format.
+pub fn compress_g1(g1: &G1Affine) -> [u8; 32] {
+    let mut g1_bytes = [0u8; 32];
+    g1.serialize_compressed(&mut g1_bytes[..])
+        .expect("Compressed G1 point is 32 bytes");
+    ark_compressed_x_to_gnark_compressed_x(&mut g1_bytes);
+    g1_bytes
+}
+
+/// Compress a G2 point in the gnark format.
+pub fn compress_g2(g2: &G2Affine) -> [u8; 64] {
+    let mut g2_bytes = [0u8; 64];
+    g2.serialize_compressed(&mut g2_bytes[..])
+        .expect("Compressed G2 point is 64 bytes");
+    ark_compressed_x_to_gnark_compressed_x(&mut g2_bytes);
+    g2_bytes
+}
+
+pub fn ark_flag_to_gnark_flag(msb: u8) -> Result<u8, ArkGroth16Error> {
+    let ark_flag = msb & ARK_MASK;
+
+    let gnark_flag = match ark_flag {
+        ARK_COMPRESSED_POSITIVE => GNARK_COMPRESSED_POSITIVE,
+        ARK_COMPRESSED_NEGATIVE => GNARK_COMPRESSED_NEGATIVE,
+        ARK_COMPRESSED_INFINITY => GNARK_COMPRESSED_INFINITY,
+        _ => {
+            return Err(ArkGroth16Error::InvalidInput);
+        }
+    };
+
+    Ok(msb & !GNARK_MASK | gnark_flag)
+}
+
+/// Convert an arkworks compressed x coordinate to the gnark format, in place.
+fn ark_compressed_x_to_gnark_compressed_x(x: &mut [u8]) {
+    x.reverse();
+    x[0] = ark_flag_to_gnark_flag(x[0]).expect("arkworks always sets a valid flag");
+}
+
 #[allow(dead_code)]
 pub fn gnark_compressed_x_to_ark_compressed_x(x: &[u8]) -> Result<Vec<u8>, ArkGroth16Error> {
     if x.len() != 32 && x.len() != 64 {
@@ -141,9 +251,68 @@ pub fn gnark_decompressed_g2_to_ark_decompressed_g2(
     Ok(g2)
 }
 
+/// Serialize an arkworks affine G1 point to a gnark decompressed affine G1 point.
+pub fn ark_decompressed_g1_to_gnark_decompressed_g1(g1: &G1Affine) -> [u8; 64] {
+    if g1.is_zero() {
+        return [0u8; 64];
+    }
+    let mut buf = [0u8; 64];
+    g1.serialize_uncompressed(&mut buf[..])
+        .expect("Uncompressed G1 point is 64 bytes");
+    convert_endianness::<32, 64>(&buf)
+}
+
+/// Serialize an arkworks affine G2 point to a gnark decompressed affine G2 point.
+pub fn ark_decompressed_g2_to_gnark_decompressed_g2(g2: &G2Affine) -> [u8; 128] {
+    if g2.is_zero() {
+        return [0u8; 128];
+    }
+    let mut buf = [0u8; 128];
+    g2.serialize_uncompressed(&mut buf[..])
+        .expect("Uncompressed G2 point is 128 bytes");
+    convert_endianness::<64, 128>(&buf)
+}
+
+/// Serialize an arkworks Groth16 proof to bytes in the gnark format.
+///
+/// This is the inverse of [`load_ark_proof_from_bytes`].
+pub fn ark_proof_to_gnark_bytes(proof: &Proof<Bn254>) -> [u8; 256] {
+    let mut buffer = [0u8; 256];
+    buffer[..64].copy_from_slice(&ark_decompressed_g1_to_gnark_decompressed_g1(&proof.a));
+    buffer[64..192].copy_from_slice(&ark_decompressed_g2_to_gnark_decompressed_g2(&proof.b));
+    buffer[192..].copy_from_slice(&ark_decompressed_g1_to_gnark_decompressed_g1(&proof.c));
+    buffer
+}
+
+/// Serialize an arkworks Groth16 verifying key to bytes in the gnark format.
+///
+/// arkworks does not keep `G1.Beta` and `G1.Delta`, so they are encoded as the point at infinity.
+/// The result therefore differs from the original gnark file, but parses to the same key with
+/// [`load_ark_groth16_verifying_key_from_bytes`].
+pub fn ark_groth16_verifying_key_to_gnark_bytes(vk: &VerifyingKey<Bn254>) -> Vec<u8> {
+    GnarkGroth16VerifyingKey::from(vk).to_bytes()
+}
+
 /// Load a Groth16 proof from bytes in the arkworks format.
 #[allow(dead_code)]
 pub fn load_ark_proof_from_bytes(buffer: &[u8]) -> Result<Proof<Bn254>, ArkGroth16Error> {
+    load_ark_proof_from_bytes_with_mode(buffer, ValidationMode::Lenient)
+}
+
+/// Load a Groth16 proof from bytes in the arkworks format, validating it according to `mode`.
+///
+/// Proof points are always checked for curve and subgroup membership. Strict mode also rejects
+/// non-canonical coordinates.
+pub fn load_ark_proof_from_bytes_with_mode(
+    buffer: &[u8],
+    mode: ValidationMode,
+) -> Result<Proof<Bn254>, ArkGroth16Error> {
+    if buffer.len() < 256 {
+        return Err(ArkGroth16Error::InvalidInput);
+    }
+    if mode == ValidationMode::Strict && !is_canonical_fq(&buffer[..256]) {
+        return Err(ArkGroth16Error::NonCanonicalEncoding);
+    }
     Ok(Proof::<Bn254> {
         a: gnark_decompressed_g1_to_ark_decompressed_g1(buffer[..64].try_into().unwrap())?,
         b: gnark_decompressed_g2_to_ark_decompressed_g2(buffer[64..192].try_into().unwrap())?,
@@ -151,53 +320,186 @@ pub fn load_ark_proof_from_bytes(buffer: &[u8]) -> Result<Proof<Bn254>, ArkGroth
     })
 }
 
+/// Cursor over a gnark encoded buffer, tracking the offset of every field it reads.
+pub(crate) struct GnarkReader<'a> {
+    buffer: &'a [u8],
+    offset: usize,
+    mode: ValidationMode,
+}
+
+impl<'a> GnarkReader<'a> {
+    pub(crate) fn new(buffer: &'a [u8], mode: ValidationMode) -> Self {
+        Self {
+            buffer,
+            offset: 0,
+            mode,
+        }
+    }
+
+    fn error(field: impl Into<String>, offset: usize, reason: &'static str) -> ArkGroth16Error {
+        ArkGroth16Error::VerifyingKeyParse {
+            field: field.into(),
+            offset,
+            reason,
+        }
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.buffer.len() - self.offset
+    }
+
+    pub(crate) fn read<const N: usize>(
+        &mut self,
+        field: &str,
+    ) -> Result<&'a [u8; N], ArkGroth16Error> {
+        let bytes = self
+            .buffer
+            .get(self.offset..self.offset + N)
+            .ok_or_else(|| Self::error(field, self.offset, "unexpected end of input"))?;
+        self.offset += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    pub(crate) fn read_u32(&mut self, field: &str) -> Result<u32, ArkGroth16Error> {
+        Ok(u32::from_be_bytes(*self.read::<4>(field)?))
+    }
+
+    pub(crate) fn read_u64(&mut self, field: &str) -> Result<u64, ArkGroth16Error> {
+        Ok(u64::from_be_bytes(*self.read::<8>(field)?))
+    }
+
+    /// Read a length prefix, rejecting counts that cannot fit in the remaining input.
+    pub(crate) fn read_len(
+        &mut self,
+        field: &str,
+        element_size: usize,
+    ) -> Result<usize, ArkGroth16Error> {
+        let offset = self.offset;
+        let len = self.read_u32(field)? as usize;
+        if len.saturating_mul(element_size) > self.remaining() {
+            return Err(Self::error(field, offset, "length exceeds input size"));
+        }
+        Ok(len)
+    }
+
+    /// Read `len` bytes, for fields whose size depends on the curve.
+    pub(crate) fn read_slice(
+        &mut self,
+        field: &str,
+        len: usize,
+    ) -> Result<&'a [u8], ArkGroth16Error> {
+        let bytes = self
+            .buffer
+            .get(self.offset..self.offset + len)
+            .ok_or_else(|| Self::error(field, self.offset, "unexpected end of input"))?;
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    /// Read a big endian scalar, rejecting non-canonical encodings.
+    pub(crate) fn read_scalar<F: PrimeField>(&mut self, field: &str) -> Result<F, ArkGroth16Error> {
+        let offset = self.offset;
+        let bytes = self.read_slice(field, F::MODULUS_BIT_SIZE.div_ceil(8) as usize)?;
+        let scalar = F::from_be_bytes_mod_order(bytes);
+        if scalar.into_bigint().to_bytes_be() != bytes {
+            return Err(Self::error(field, offset, "non-canonical field encoding"));
+        }
+        Ok(scalar)
+    }
+
+    pub(crate) fn read_g1<E: GnarkCurve>(
+        &mut self,
+        field: &str,
+    ) -> Result<E::G1Affine, ArkGroth16Error> {
+        let offset = self.offset;
+        E::g1_from_gnark_compressed(self.read_slice(field, E::G1_SIZE)?, self.mode)
+            .map_err(|e| Self::point_error(field, offset, e))
+    }
+
+    pub(crate) fn read_g2<E: GnarkCurve>(
+        &mut self,
+        field: &str,
+    ) -> Result<E::G2Affine, ArkGroth16Error> {
+        let offset = self.offset;
+        E::g2_from_gnark_compressed(self.read_slice(field, 2 * E::G1_SIZE)?, self.mode)
+            .map_err(|e| Self::point_error(field, offset, e))
+    }
+
+    /// Read a G1 point in the gnark uncompressed encoding, as used by proofs.
+    pub(crate) fn read_g1_uncompressed<E: GnarkCurve>(
+        &mut self,
+        field: &str,
+    ) -> Result<E::G1Affine, ArkGroth16Error> {
+        let offset = self.offset;
+        E::g1_from_gnark_uncompressed(self.read_slice(field, 2 * E::G1_SIZE)?, self.mode)
+            .map_err(|e| Self::uncompressed_point_error(field, offset, e))
+    }
+
+    /// Read a G2 point in the gnark uncompressed encoding, as used by proofs.
+    pub(crate) fn read_g2_uncompressed<E: GnarkCurve>(
+        &mut self,
+        field: &str,
+    ) -> Result<E::G2Affine, ArkGroth16Error> {
+        let offset = self.offset;
+        E::g2_from_gnark_uncompressed(self.read_slice(field, 4 * E::G1_SIZE)?, self.mode)
+            .map_err(|e| Self::uncompressed_point_error(field, offset, e))
+    }
+
+    fn uncompressed_point_error(
+        field: &str,
+        offset: usize,
+        error: ArkGroth16Error,
+    ) -> ArkGroth16Error {
+        match error {
+            ArkGroth16Error::NonCanonicalEncoding => {
+                Self::error(field, offset, "non-canonical field encoding")
+            }
+            _ => Self::error(field, offset, "not on curve or not in subgroup"),
+        }
+    }
+
+    fn point_error(field: &str, offset: usize, error: ArkGroth16Error) -> ArkGroth16Error {
+        match error {
+            ArkGroth16Error::InvalidInput => Self::error(field, offset, "invalid compression flag"),
+            ArkGroth16Error::NonCanonicalEncoding => {
+                Self::error(field, offset, "non-canonical field encoding")
+            }
+            ArkGroth16Error::NotInSubgroup => Self::error(field, offset, "not in subgroup"),
+            _ => Self::error(field, offset, "not on curve"),
+        }
+    }
+
+    pub(crate) fn finish(self) -> Result<(), ArkGroth16Error> {
+        if self.remaining() != 0 {
+            return Err(Self::error(
+                "end of buffer",
+                self.offset,
+                "unexpected trailing bytes",
+            ));
+        }
+        Ok(())
+    }
+}
+
 /// Load a Groth16 verifying key from bytes in the arkworks format.
+///
+/// The buffer is the gnark binary encoding of a BN254 Groth16 verifying key. Every field is
+/// bounds checked, and the buffer must not contain trailing bytes. See
+/// [`GnarkGroth16VerifyingKey`] to keep the fields arkworks does not use.
 #[allow(dead_code)]
 pub fn load_ark_groth16_verifying_key_from_bytes(
     buffer: &[u8],
 ) -> Result<VerifyingKey<Bn254>, ArkGroth16Error> {
-    // Note that g1_beta and g1_delta are not used in the verification process.
-    let alpha_g1 = decompress_g1(buffer[..32].try_into().unwrap())?;
-    let beta_g2 = decompress_g2(buffer[64..128].try_into().unwrap())?;
-    let gamma_g2 = decompress_g2(buffer[128..192].try_into().unwrap())?;
-    let delta_g2 = decompress_g2(buffer[224..288].try_into().unwrap())?;
-
-    let num_k = u32::from_be_bytes([buffer[288], buffer[289], buffer[290], buffer[291]]);
-    let mut k = Vec::new();
-    let mut offset = 292;
-    for _ in 0..num_k {
-        let point = decompress_g1(&buffer[offset..offset + 32].try_into().unwrap())?;
-        k.push(point);
-        offset += 32;
-    }
-
-    let num_of_array_of_public_and_commitment_committed = u32::from_be_bytes([
-        buffer[offset],
-        buffer[offset + 1],
-        buffer[offset + 2],
-        buffer[offset + 3],
-    ]);
-    offset += 4;
-    for _ in 0..num_of_array_of_public_and_commitment_committed {
-        let num = u32::from_be_bytes([
-            buffer[offset],
-            buffer[offset + 1],
-            buffer[offset + 2],
-            buffer[offset + 3],
-        ]);
-        offset += 4;
-        for _ in 0..num {
-            offset += 4;
-        }
-    }
+    load_ark_groth16_verifying_key_from_bytes_with_mode(buffer, ValidationMode::Lenient)
+}
 
-    Ok(VerifyingKey {
-        alpha_g1,
-        beta_g2,
-        gamma_g2,
-        delta_g2,
-        gamma_abc_g1: k,
-    })
+/// Load a Groth16 verifying key from bytes in the arkworks format, validating every point
+/// according to `mode`.
+pub fn load_ark_groth16_verifying_key_from_bytes_with_mode(
+    buffer: &[u8],
+    mode: ValidationMode,
+) -> Result<VerifyingKey<Bn254>, ArkGroth16Error> {
+    GnarkGroth16VerifyingKey::from_bytes_with_mode(buffer, mode).map(Into::into)
 }
 
 /// Load the public inputs from the bytes in the arkworks format.
@@ -213,3 +515,14 @@ pub fn load_ark_public_inputs_from_bytes(
         Fr::from_be_bytes_mod_order(committed_values_digest),
     ]
 }
+
+/// Load any number of public inputs from the bytes in the arkworks format.
+///
+/// Each input is read as a big endian Fr element, in the order of the wrapper circuit. See
+/// [`crate::sp1_public_input_schema`] for the meaning of each input.
+pub fn load_ark_public_inputs(public_inputs: &[[u8; 32]]) -> Vec<Fr> {
+    public_inputs
+        .iter()
+        .map(|input| Fr::from_be_bytes_mod_order(input))
+        .collect()
+}
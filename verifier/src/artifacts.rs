@@ -0,0 +1,126 @@
+#[cfg(feature = "base64")]
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ConvertError, Sp1PublicInput, Sp1Version, SuiVerification, SuiVerifyError,
+    verify_public_values, verify_sui_artifacts,
+};
+
+/// The output of the SP1 to Sui conversion.
+///
+/// The `verifying_key`, `public_inputs` and `proof_points` bytes are the three arguments of the
+/// `verify_groth16_bn254_proof` Move function, in that order. The remaining fields describe the
+/// proof the artifacts were produced from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SuiGroth16Artifacts {
+    /// Compressed arkworks Groth16 verifying key.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub verifying_key: Vec<u8>,
+    /// Concatenated 32 byte little endian public inputs.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub public_inputs: Vec<u8>,
+    /// Compressed arkworks Groth16 proof points.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub proof_points: Vec<u8>,
+    /// SP1 version of the Groth16 verifying key used for the conversion, `custom` for a key that
+    /// is not embedded and `mock` for a mock proof.
+    pub sp1_version: String,
+    /// Program vkey hash, as returned by `SP1VerifyingKey::bytes32`.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub vkey_hash: [u8; 32],
+    /// Digest of the public values committed by the program.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub committed_values_digest: [u8; 32],
+    /// Raw public values of the program.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub public_values: Vec<u8>,
+    /// Whether the artifacts were converted from a mock proof. Mock artifacts carry the public
+    /// inputs but no verifying key or proof points, and never verify on-chain.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mock: bool,
+}
+
+impl SuiGroth16Artifacts {
+    /// Program vkey hash as a `0x` prefixed hex string.
+    pub fn vkey_hash_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.vkey_hash))
+    }
+
+    /// The SP1 public input of each 32 byte chunk of `public_inputs`, in order, given by
+    /// `sp1_version`.
+    ///
+    /// Returns `None` if `sp1_version` is not a known SP1 version, such as for a custom verifying
+    /// key or a mock proof.
+    pub fn public_input_schema(&self) -> Option<&'static [Sp1PublicInput]> {
+        self.sp1_version
+            .parse::<Sp1Version>()
+            .ok()
+            .map(Sp1Version::public_input_schema)
+    }
+
+    /// Check the public values against the committed values digest and return them.
+    ///
+    /// Use this before decoding application data from artifacts that were not produced locally.
+    pub fn verified_public_values(&self) -> Result<&[u8], ConvertError> {
+        verify_public_values(&self.public_values, &self.committed_values_digest)?;
+        Ok(&self.public_values)
+    }
+
+    /// Verify the verifying key, public inputs and proof points as the Sui verifier would.
+    ///
+    /// See [`verify_sui_artifacts`].
+    pub fn verify(&self) -> Result<SuiVerification, SuiVerifyError> {
+        if self.mock {
+            return Err(SuiVerifyError::MockProof);
+        }
+        verify_sui_artifacts(&self.verifying_key, &self.public_inputs, &self.proof_points)
+    }
+
+    /// Compressed arkworks Groth16 verifying key, hex encoded without a `0x` prefix.
+    pub fn verifying_key_hex(&self) -> String {
+        hex::encode(&self.verifying_key)
+    }
+
+    /// Little endian public inputs, hex encoded without a `0x` prefix.
+    pub fn public_inputs_hex(&self) -> String {
+        hex::encode(&self.public_inputs)
+    }
+
+    /// Compressed arkworks Groth16 proof points, hex encoded without a `0x` prefix.
+    pub fn proof_points_hex(&self) -> String {
+        hex::encode(&self.proof_points)
+    }
+
+    /// Compressed arkworks Groth16 verifying key, standard base64 encoded.
+    #[cfg(feature = "base64")]
+    pub fn verifying_key_base64(&self) -> String {
+        BASE64.encode(&self.verifying_key)
+    }
+
+    /// Little endian public inputs, standard base64 encoded.
+    #[cfg(feature = "base64")]
+    pub fn public_inputs_base64(&self) -> String {
+        BASE64.encode(&self.public_inputs)
+    }
+
+    /// Compressed arkworks Groth16 proof points, standard base64 encoded.
+    #[cfg(feature = "base64")]
+    pub fn proof_points_base64(&self) -> String {
+        BASE64.encode(&self.proof_points)
+    }
+
+    /// The BCS bytes of the pure arguments of `verify_groth16_bn254_proof`, in the order
+    /// verifying key, public inputs, proof points. Each element is the BCS encoding of a
+    /// `vector<u8>`, to be wrapped in `CallArg::Pure` when building a transaction.
+    #[cfg(feature = "serde")]
+    pub fn pure_args(&self) -> [Vec<u8>; 3] {
+        [
+            bcs::to_bytes(&self.verifying_key).expect("BCS encoding of bytes is infallible"),
+            bcs::to_bytes(&self.public_inputs).expect("BCS encoding of bytes is infallible"),
+            bcs::to_bytes(&self.proof_points).expect("BCS encoding of bytes is infallible"),
+        ]
+    }
+}
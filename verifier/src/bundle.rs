@@ -0,0 +1,279 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::SuiGroth16Artifacts;
+
+/// Version of the bundle format written by this crate.
+///
+/// The version is bumped on incompatible changes. Readers accept the versions they know how to
+/// decode and reject any other with [`BundleError::UnsupportedVersion`]. Fields added to the JSON
+/// encoding without a version bump are ignored by older readers.
+pub const SUI_PROOF_BUNDLE_VERSION: u32 = 1;
+
+/// Magic bytes at the start of the binary bundle encoding.
+pub const SUI_PROOF_BUNDLE_MAGIC: [u8; 8] = *b"sp1-sui\0";
+
+/// Errors returned when encoding, decoding, saving or loading a [`SuiProofBundle`].
+#[derive(Error, Debug)]
+pub enum BundleError {
+    #[error("Failed to access Sui proof bundle {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("Invalid Sui proof bundle JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid Sui proof bundle encoding: {0}")]
+    Binary(#[from] bcs::Error),
+    #[error("Not a Sui proof bundle: missing magic bytes")]
+    InvalidMagic,
+    #[error(
+        "Unsupported Sui proof bundle version {version}, only version {supported} is supported"
+    )]
+    UnsupportedVersion { version: u32, supported: u32 },
+}
+
+/// Converted Sui Groth16 artifacts together with the program and converter they come from.
+///
+/// A bundle is saved as JSON or as a compact binary encoding, see [`SuiProofBundle::save`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuiProofBundle {
+    /// Bundle format version, see [`SUI_PROOF_BUNDLE_VERSION`].
+    pub version: u32,
+    /// Version of `sp1-sui` that converted the proof.
+    pub converter_version: String,
+    /// The Sui verifier arguments, program vkey hash, SP1 version and public values.
+    pub artifacts: SuiGroth16Artifacts,
+}
+
+/// The version header shared by every bundle version.
+#[derive(Deserialize)]
+struct BundleHeader {
+    version: u32,
+}
+
+/// Binary encoding of a version 1 bundle. Unlike the JSON encoding, bytes are not hex encoded.
+#[derive(Serialize, Deserialize)]
+struct BinaryBundleV1 {
+    converter_version: String,
+    verifying_key: Vec<u8>,
+    public_inputs: Vec<u8>,
+    proof_points: Vec<u8>,
+    sp1_version: String,
+    vkey_hash: [u8; 32],
+    committed_values_digest: [u8; 32],
+    public_values: Vec<u8>,
+    mock: bool,
+}
+
+fn check_version(version: u32) -> Result<(), BundleError> {
+    if version != SUI_PROOF_BUNDLE_VERSION {
+        return Err(BundleError::UnsupportedVersion {
+            version,
+            supported: SUI_PROOF_BUNDLE_VERSION,
+        });
+    }
+    Ok(())
+}
+
+impl SuiProofBundle {
+    /// Bundle artifacts converted by this version of `sp1-sui`.
+    pub fn new(artifacts: SuiGroth16Artifacts) -> Self {
+        Self {
+            version: SUI_PROOF_BUNDLE_VERSION,
+            converter_version: env!("CARGO_PKG_VERSION").to_string(),
+            artifacts,
+        }
+    }
+
+    /// Encode the bundle as pretty printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("JSON encoding of a bundle is infallible")
+    }
+
+    /// Decode a bundle from JSON.
+    pub fn from_json(json: &str) -> Result<Self, BundleError> {
+        check_version(serde_json::from_str::<BundleHeader>(json)?.version)?;
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Encode the bundle in the compact binary encoding: [`SUI_PROOF_BUNDLE_MAGIC`], the version
+    /// as a little endian `u32` and the BCS encoding of the fields.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let artifacts = self.artifacts.clone();
+        let body = BinaryBundleV1 {
+            converter_version: self.converter_version.clone(),
+            verifying_key: artifacts.verifying_key,
+            public_inputs: artifacts.public_inputs,
+            proof_points: artifacts.proof_points,
+            sp1_version: artifacts.sp1_version,
+            vkey_hash: artifacts.vkey_hash,
+            committed_values_digest: artifacts.committed_values_digest,
+            public_values: artifacts.public_values,
+            mock: artifacts.mock,
+        };
+        let mut bytes = SUI_PROOF_BUNDLE_MAGIC.to_vec();
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        bytes.extend(bcs::to_bytes(&body).expect("BCS encoding of a bundle is infallible"));
+        bytes
+    }
+
+    /// Decode a bundle from the compact binary encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BundleError> {
+        let body = bytes
+            .strip_prefix(&SUI_PROOF_BUNDLE_MAGIC)
+            .ok_or(BundleError::InvalidMagic)?;
+        let (version, body) = body
+            .split_first_chunk::<4>()
+            .ok_or(BundleError::InvalidMagic)?;
+        let version = u32::from_le_bytes(*version);
+        check_version(version)?;
+
+        let body: BinaryBundleV1 = bcs::from_bytes(body)?;
+        Ok(Self {
+            version,
+            converter_version: body.converter_version,
+            artifacts: SuiGroth16Artifacts {
+                verifying_key: body.verifying_key,
+                public_inputs: body.public_inputs,
+                proof_points: body.proof_points,
+                sp1_version: body.sp1_version,
+                vkey_hash: body.vkey_hash,
+                committed_values_digest: body.committed_values_digest,
+                public_values: body.public_values,
+                mock: body.mock,
+            },
+        })
+    }
+
+    /// Save the bundle to a file, as JSON if the path has a `json` extension and in the binary
+    /// encoding otherwise.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), BundleError> {
+        let path = path.as_ref();
+        let bytes = if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            self.to_json().into_bytes()
+        } else {
+            self.to_bytes()
+        };
+        fs::write(path, bytes).map_err(|source| BundleError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Load a bundle saved with [`SuiProofBundle::save`], in either encoding.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, BundleError> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|source| BundleError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        if bytes.starts_with(&SUI_PROOF_BUNDLE_MAGIC) {
+            return Self::from_bytes(&bytes);
+        }
+        let json = String::from_utf8(bytes).map_err(|_| BundleError::InvalidMagic)?;
+        Self::from_json(&json)
+    }
+}
+
+impl From<SuiGroth16Artifacts> for SuiProofBundle {
+    fn from(artifacts: SuiGroth16Artifacts) -> Self {
+        Self::new(artifacts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle() -> SuiProofBundle {
+        SuiProofBundle::new(SuiGroth16Artifacts {
+            verifying_key: vec![1; 3],
+            public_inputs: vec![2; 64],
+            proof_points: vec![3; 5],
+            sp1_version: "v5.0.0".to_string(),
+            vkey_hash: [4; 32],
+            committed_values_digest: [5; 32],
+            public_values: vec![6, 7],
+            mock: false,
+        })
+    }
+
+    #[test]
+    fn test_check_version() {
+        assert!(check_version(SUI_PROOF_BUNDLE_VERSION).is_ok());
+        for version in [0, SUI_PROOF_BUNDLE_VERSION + 1, u32::MAX] {
+            assert!(matches!(
+                check_version(version),
+                Err(BundleError::UnsupportedVersion { version: v, supported })
+                    if v == version && supported == SUI_PROOF_BUNDLE_VERSION
+            ));
+        }
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let bundle = bundle();
+        let json = bundle.to_json();
+        assert_eq!(SuiProofBundle::from_json(&json).unwrap(), bundle);
+
+        // The version is checked before the rest of the bundle is decoded.
+        assert!(matches!(
+            SuiProofBundle::from_json(r#"{"version": 7}"#),
+            Err(BundleError::UnsupportedVersion { version: 7, .. })
+        ));
+        assert!(matches!(
+            SuiProofBundle::from_json(r#"{"version": 1}"#),
+            Err(BundleError::Json(_))
+        ));
+        assert!(matches!(
+            SuiProofBundle::from_json("[]"),
+            Err(BundleError::Json(_))
+        ));
+
+        // JSON without a `mock` field decodes with `mock` set to `false`.
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["artifacts"].as_object_mut().unwrap().remove("mock");
+        assert_eq!(
+            SuiProofBundle::from_json(&value.to_string()).unwrap(),
+            bundle
+        );
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let mut bundle = bundle();
+        bundle.artifacts.mock = true;
+        let bytes = bundle.to_bytes();
+        assert!(bytes.starts_with(&SUI_PROOF_BUNDLE_MAGIC));
+        assert_eq!(bytes[8..12], SUI_PROOF_BUNDLE_VERSION.to_le_bytes());
+        assert_eq!(SuiProofBundle::from_bytes(&bytes).unwrap(), bundle);
+
+        assert!(matches!(
+            SuiProofBundle::from_bytes(b""),
+            Err(BundleError::InvalidMagic)
+        ));
+        assert!(matches!(
+            SuiProofBundle::from_bytes(&bytes[..10]),
+            Err(BundleError::InvalidMagic)
+        ));
+        assert!(matches!(
+            SuiProofBundle::from_bytes(&bytes[..12]),
+            Err(BundleError::Binary(_))
+        ));
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            SuiProofBundle::from_bytes(&trailing),
+            Err(BundleError::Binary(_))
+        ));
+    }
+}
@@ -0,0 +1,356 @@
+use std::fs;
+use std::path::Path;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ff::PrimeField;
+use ark_groth16::{Groth16, Proof, VerifyingKey, prepare_verifying_key};
+use ark_serialize::CanonicalSerialize;
+use ark_snark::SNARK;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::ark_converter::{ArkGroth16Error, GnarkReader, ValidationMode};
+use crate::curve::{GnarkCurve, SuiCurve};
+use crate::gnark::{GnarkGroth16Proof, GnarkGroth16VerifyingKey};
+use crate::{
+    ConvertError, FoldedGroth16, SuiVerifyError, fold_bsb22_commitment, verify_sui_groth16,
+};
+
+/// The output of the conversion of a gnark Groth16 proof of any circuit.
+///
+/// The `verifying_key`, `public_inputs` and `proof_points` bytes are the arguments of
+/// `groth16::prepare_verifying_key`, `groth16::public_proof_inputs_from_bytes` and
+/// `groth16::proof_points_from_bytes`, with the `groth16::bn254()` or `groth16::bls12381()` curve
+/// given by `curve`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SuiCircuitArtifacts {
+    /// The curve of the circuit.
+    pub curve: SuiCurve,
+    /// Compressed arkworks Groth16 verifying key.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub verifying_key: Vec<u8>,
+    /// Concatenated 32 byte little endian public inputs.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub public_inputs: Vec<u8>,
+    /// Compressed arkworks Groth16 proof points.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub proof_points: Vec<u8>,
+}
+
+impl SuiCircuitArtifacts {
+    /// Verify the verifying key, public inputs and proof points as the Sui verifier would.
+    ///
+    /// See [`verify_sui_groth16`].
+    pub fn verify(&self) -> Result<(), SuiVerifyError> {
+        let (vk, inputs, proof) = (&self.verifying_key, &self.public_inputs, &self.proof_points);
+        match self.curve {
+            SuiCurve::Bn254 => verify_sui_groth16::<Bn254>(vk, inputs, proof).map(drop),
+            SuiCurve::Bls12381 => verify_sui_groth16::<Bls12_381>(vk, inputs, proof).map(drop),
+        }
+    }
+
+    /// Compressed arkworks Groth16 verifying key, hex encoded without a `0x` prefix.
+    pub fn verifying_key_hex(&self) -> String {
+        hex::encode(&self.verifying_key)
+    }
+
+    /// Little endian public inputs, hex encoded without a `0x` prefix.
+    pub fn public_inputs_hex(&self) -> String {
+        hex::encode(&self.public_inputs)
+    }
+
+    /// Compressed arkworks Groth16 proof points, hex encoded without a `0x` prefix.
+    pub fn proof_points_hex(&self) -> String {
+        hex::encode(&self.proof_points)
+    }
+
+    /// The BCS bytes of the pure verifying key, public inputs and proof points arguments. Each
+    /// element is the BCS encoding of a `vector<u8>`, to be wrapped in `CallArg::Pure`.
+    #[cfg(feature = "serde")]
+    pub fn pure_args(&self) -> [Vec<u8>; 3] {
+        [
+            bcs::to_bytes(&self.verifying_key).expect("BCS encoding of bytes is infallible"),
+            bcs::to_bytes(&self.public_inputs).expect("BCS encoding of bytes is infallible"),
+            bcs::to_bytes(&self.proof_points).expect("BCS encoding of bytes is infallible"),
+        ]
+    }
+}
+
+/// Load the public inputs from a gnark witness, as written by `witness.WriteTo`.
+///
+/// The encoding is the number of public and secret variables as big endian `u32`, followed by a
+/// length prefixed vector of big endian scalars, public variables first. A full witness is
+/// accepted too, and only its public variables are returned. Non-canonical scalars are rejected.
+pub fn load_gnark_public_witness<F: PrimeField>(buffer: &[u8]) -> Result<Vec<F>, ArkGroth16Error> {
+    let mut reader = GnarkReader::new(buffer, ValidationMode::Strict);
+    let num_public = reader.read_u32("nbPublic")? as usize;
+    let num_secret = reader.read_u32("nbSecret")? as usize;
+    let offset = buffer.len() - reader.remaining();
+    let len = reader.read_len("len(Vector)", F::MODULUS_BIT_SIZE.div_ceil(8) as usize)?;
+    if len != num_public.saturating_add(num_secret) {
+        return Err(ArkGroth16Error::VerifyingKeyParse {
+            field: "len(Vector)".to_string(),
+            offset,
+            reason: "length does not match the number of public and secret variables",
+        });
+    }
+
+    let mut public_inputs = Vec::with_capacity(num_public);
+    for i in 0..len {
+        let scalar = reader.read_scalar::<F>(&format!("Vector[{i}]"))?;
+        if i < num_public {
+            public_inputs.push(scalar);
+        }
+    }
+    reader.finish()?;
+    Ok(public_inputs)
+}
+
+/// Convert a gnark Groth16 proof of any circuit to the arkworks format expected by the Sui
+/// verifier.
+///
+/// `groth16_vk` is the verifying key written by `VerifyingKey.WriteTo`, `proof` is written by
+/// either `Proof.WriteRawTo` or `Proof.WriteTo`, and `public_witness` by `witness.WriteTo`, see
+/// [`load_gnark_public_witness`]. The proof is checked against the verifying key before it is
+/// returned. A BSB22 commitment is folded into the verifying key as for SP1 proofs, see
+/// [`fold_bsb22_commitment`].
+pub fn convert_gnark_groth16(
+    curve: SuiCurve,
+    groth16_vk: &[u8],
+    proof: &[u8],
+    public_witness: &[u8],
+) -> Result<SuiCircuitArtifacts, ConvertError> {
+    convert_gnark_groth16_with_mode(
+        curve,
+        groth16_vk,
+        proof,
+        public_witness,
+        ValidationMode::default(),
+    )
+}
+
+/// Convert a gnark Groth16 proof of any circuit, validating the verifying key and proof points
+/// according to `mode`.
+///
+/// See [`convert_gnark_groth16`].
+pub fn convert_gnark_groth16_with_mode(
+    curve: SuiCurve,
+    groth16_vk: &[u8],
+    proof: &[u8],
+    public_witness: &[u8],
+    mode: ValidationMode,
+) -> Result<SuiCircuitArtifacts, ConvertError> {
+    match curve {
+        SuiCurve::Bn254 => convert_circuit::<Bn254>(groth16_vk, proof, public_witness, mode),
+        SuiCurve::Bls12381 => convert_circuit::<Bls12_381>(groth16_vk, proof, public_witness, mode),
+    }
+}
+
+/// Convert the gnark verifying key, proof and public witness files of a circuit.
+///
+/// See [`convert_gnark_groth16`].
+pub fn convert_gnark_groth16_files(
+    curve: SuiCurve,
+    vk_path: impl AsRef<Path>,
+    proof_path: impl AsRef<Path>,
+    public_witness_path: impl AsRef<Path>,
+) -> Result<SuiCircuitArtifacts, ConvertError> {
+    let read = |path: &Path| {
+        fs::read(path).map_err(|source| ConvertError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    };
+    convert_gnark_groth16(
+        curve,
+        &read(vk_path.as_ref())?,
+        &read(proof_path.as_ref())?,
+        &read(public_witness_path.as_ref())?,
+    )
+}
+
+fn convert_circuit<E: GnarkCurve>(
+    groth16_vk: &[u8],
+    proof: &[u8],
+    public_witness: &[u8],
+    mode: ValidationMode,
+) -> Result<SuiCircuitArtifacts, ConvertError> {
+    let gnark_proof = if GnarkGroth16Proof::<E>::is_compressed(proof) {
+        GnarkGroth16Proof::from_compressed_bytes_with_mode(proof, mode)
+    } else {
+        GnarkGroth16Proof::from_bytes_with_mode(proof, mode)
+    }
+    .map_err(ConvertError::InvalidProof)?;
+    let gnark_vk = GnarkGroth16VerifyingKey::<E>::from_bytes_with_mode(groth16_vk, mode)
+        .map_err(ConvertError::InvalidVerifyingKey)?;
+    let public_inputs = load_gnark_public_witness::<E::ScalarField>(public_witness)
+        .map_err(ConvertError::InvalidPublicWitness)?;
+
+    convert_gnark_parts(&gnark_vk, &gnark_proof, &public_inputs)
+}
+
+/// Fold the commitment of the proof into the verifying key if the circuit has one, check that
+/// the proof verifies and serialize the verifying key, public inputs and proof points.
+///
+/// `public_inputs` are the public inputs of the circuit, without the commitment hash.
+pub(crate) fn convert_gnark_parts<E: GnarkCurve>(
+    gnark_vk: &GnarkGroth16VerifyingKey<E>,
+    gnark_proof: &GnarkGroth16Proof<E>,
+    public_inputs: &[E::ScalarField],
+) -> Result<SuiCircuitArtifacts, ConvertError> {
+    // Ark Public Inputs, as many as the verifying key expects besides the commitment hashes.
+    let expected = gnark_vk.num_public_inputs();
+    if public_inputs.len() != expected {
+        return Err(ConvertError::PublicInputCountMismatch {
+            expected,
+            actual: public_inputs.len(),
+        });
+    }
+
+    // Ark Groth16, with the BSB22 commitment of the proof folded in if the circuit has one.
+    let FoldedGroth16 {
+        verifying_key: ark_groth16_vk,
+        public_inputs: ark_public_inputs,
+    } = fold_bsb22_commitment(gnark_vk, gnark_proof, public_inputs)?;
+
+    verified_artifacts(
+        &ark_groth16_vk,
+        &ark_public_inputs,
+        &Proof::from(gnark_proof),
+    )
+}
+
+/// Check that an arkworks proof verifies and serialize the verifying key, public inputs and
+/// proof points for the Sui verifier.
+pub(crate) fn verified_artifacts<E: GnarkCurve>(
+    ark_groth16_vk: &VerifyingKey<E>,
+    ark_public_inputs: &[E::ScalarField],
+    ark_proof: &Proof<E>,
+) -> Result<SuiCircuitArtifacts, ConvertError> {
+    let expected = ark_groth16_vk.gamma_abc_g1.len().saturating_sub(1);
+    if ark_public_inputs.len() != expected {
+        return Err(ConvertError::PublicInputCountMismatch {
+            expected,
+            actual: ark_public_inputs.len(),
+        });
+    }
+
+    // Verify Ark proof
+    let ark_pvk = prepare_verifying_key(ark_groth16_vk);
+    let ark_verified =
+        Groth16::<E>::verify_with_processed_vk(&ark_pvk, ark_public_inputs, ark_proof)
+            .map_err(|_| ConvertError::VerificationFailed)?;
+    if !ark_verified {
+        return Err(ConvertError::VerificationFailed);
+    }
+
+    // Bytes to pass on to the Sui verifier
+    let mut ark_groth16_serialized = Vec::new();
+    ark_groth16_vk.serialize_compressed(&mut ark_groth16_serialized)?;
+    let mut ark_public_inputs_serialized = Vec::new();
+    for input in ark_public_inputs {
+        input.serialize_compressed(&mut ark_public_inputs_serialized)?;
+    }
+    let mut ark_proof_serialized = Vec::new();
+    ark_proof.serialize_compressed(&mut ark_proof_serialized)?;
+
+    Ok(SuiCircuitArtifacts {
+        curve: E::SUI_CURVE,
+        verifying_key: ark_groth16_serialized,
+        public_inputs: ark_public_inputs_serialized,
+        proof_points: ark_proof_serialized,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bls12_381::Fr as Bls12381Fr;
+    use ark_bn254::Fr;
+    use ark_ff::BigInteger;
+
+    use super::*;
+
+    /// A gnark witness with the given number of public variables, as written by `witness.WriteTo`.
+    fn witness<F: PrimeField>(num_public: u32, values: &[F]) -> Vec<u8> {
+        let mut buffer = num_public.to_be_bytes().to_vec();
+        buffer.extend((values.len() as u32 - num_public).to_be_bytes());
+        buffer.extend((values.len() as u32).to_be_bytes());
+        for value in values {
+            buffer.extend(value.into_bigint().to_bytes_be());
+        }
+        buffer
+    }
+
+    fn parse_error_field(result: Result<Vec<Fr>, ArkGroth16Error>) -> (String, usize) {
+        match result {
+            Err(ArkGroth16Error::VerifyingKeyParse { field, offset, .. }) => (field, offset),
+            result => panic!("unexpected result {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_load_gnark_public_witness() {
+        let values = [Fr::from(3u64), Fr::from(11u64), -Fr::from(1u64)];
+
+        // Only the public variables of a full witness are returned.
+        let public = load_gnark_public_witness::<Fr>(&witness(2, &values)).unwrap();
+        assert_eq!(public, values[..2]);
+        let public = load_gnark_public_witness::<Fr>(&witness(3, &values)).unwrap();
+        assert_eq!(public, values);
+        assert!(
+            load_gnark_public_witness::<Fr>(&witness(0, &values))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            load_gnark_public_witness::<Fr>(&witness::<Fr>(0, &[]))
+                .unwrap()
+                .is_empty()
+        );
+
+        // BLS12-381 scalars are 32 bytes too.
+        let values = [Bls12381Fr::from(7u64), -Bls12381Fr::from(1u64)];
+        let public = load_gnark_public_witness::<Bls12381Fr>(&witness(1, &values)).unwrap();
+        assert_eq!(public, values[..1]);
+    }
+
+    #[test]
+    fn test_load_malformed_gnark_public_witness() {
+        let values = [Fr::from(3u64), Fr::from(11u64)];
+        let buffer = witness(1, &values);
+
+        // The vector length must match the variable counts.
+        let mut mismatch = buffer.clone();
+        mismatch[7] = 3;
+        assert_eq!(
+            parse_error_field(load_gnark_public_witness(&mismatch)),
+            ("len(Vector)".to_string(), 8)
+        );
+
+        // Scalars at or above the modulus are rejected rather than reduced.
+        let mut non_canonical = buffer.clone();
+        non_canonical[44..76].fill(0xFF);
+        assert_eq!(
+            parse_error_field(load_gnark_public_witness(&non_canonical)),
+            ("Vector[1]".to_string(), 44)
+        );
+
+        // Truncated and trailing bytes are rejected.
+        assert_eq!(
+            parse_error_field(load_gnark_public_witness(&buffer[..6])),
+            ("nbSecret".to_string(), 4)
+        );
+        assert_eq!(
+            parse_error_field(load_gnark_public_witness(&buffer[..buffer.len() - 1])),
+            ("len(Vector)".to_string(), 8)
+        );
+        let mut trailing = buffer;
+        trailing.push(0);
+        assert_eq!(
+            parse_error_field(load_gnark_public_witness(&trailing)),
+            ("end of buffer".to_string(), 76)
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use ark_bn254::{Bn254, Fr, G1Affine};
+use ark_ec::pairing::Pairing;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_groth16::VerifyingKey;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::curve::GnarkCurve;
+use crate::gnark::{GnarkGroth16Proof, GnarkGroth16VerifyingKey, GnarkPedersenVerifyingKey};
+
+/// Domain separation tag gnark uses to hash BSB22 commitments to the scalar field.
+pub const BSB22_COMMITMENT_DST: &[u8] = b"bsb22-commitment";
+
+/// Errors returned when folding the Pedersen commitment of a gnark proof into its verifying key.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CommitmentError {
+    #[error(
+        "Groth16 circuits with {0} commitments are unsupported, only circuits with a single \
+         BSB22 commitment can be converted"
+    )]
+    Unsupported(usize),
+    #[error("Verifying key has {keys} commitment keys for {commitments} commitments")]
+    InvalidVerifyingKey { keys: usize, commitments: usize },
+    #[error("Verifying key expects {expected} commitments, the proof has {actual}")]
+    CountMismatch { expected: usize, actual: usize },
+    #[error("Committed public input index {0} is out of range")]
+    InvalidCommittedIndex(u64),
+    #[error("Pedersen commitment proof of knowledge failed verification")]
+    InvalidProofOfKnowledge,
+}
+
+/// A proof-specific verifying key and public inputs for a plain Groth16 verifier.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FoldedGroth16<E: Pairing = Bn254> {
+    /// The verifying key with the commitment added to `gamma_abc_g1[0]`.
+    pub verifying_key: VerifyingKey<E>,
+    /// The public inputs followed by the commitment hash.
+    pub public_inputs: Vec<E::ScalarField>,
+}
+
+/// `expand_message_xmd` of RFC 9380 with SHA-256. `dst` must be at most 255 bytes.
+pub(crate) fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+    let b_0 = Sha256::new()
+        .chain_update([0u8; 64])
+        .chain_update(msg)
+        .chain_update((len as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(len);
+    let mut b_i = Sha256::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=len.div_ceil(32) {
+        let xor: Vec<u8> = b_0.iter().zip(&b_i).map(|(a, b)| a ^ b).collect();
+        b_i = Sha256::new()
+            .chain_update(xor)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len);
+    uniform_bytes
+}
+
+/// Hash a message to a BN254 scalar as gnark's `fr.Hash` does: 48 bytes of
+/// `expand_message_xmd` with SHA-256, reduced modulo r.
+pub fn hash_to_field(msg: &[u8], dst: &[u8]) -> Fr {
+    hash_to_scalar(msg, dst)
+}
+
+/// [`hash_to_field`] over any 32 byte scalar field, such as the BLS12-381 one.
+fn hash_to_scalar<F: PrimeField>(msg: &[u8], dst: &[u8]) -> F {
+    F::from_be_bytes_mod_order(&expand_message_xmd(msg, dst, 48))
+}
+
+/// The public input derived from a BSB22 commitment: the hash of the uncompressed commitment
+/// followed by the committed public inputs.
+pub fn bsb22_commitment_hash(commitment: &G1Affine, committed_public_inputs: &[Fr]) -> Fr {
+    commitment_hash::<Bn254>(commitment, committed_public_inputs)
+}
+
+/// [`bsb22_commitment_hash`] over any curve.
+fn commitment_hash<E: GnarkCurve>(
+    commitment: &E::G1Affine,
+    committed_public_inputs: &[E::ScalarField],
+) -> E::ScalarField {
+    let mut prehash = E::g1_to_gnark_uncompressed(commitment);
+    for input in committed_public_inputs {
+        prehash.extend_from_slice(&input.into_bigint().to_bytes_be());
+    }
+    hash_to_scalar(&prehash, BSB22_COMMITMENT_DST)
+}
+
+/// Verify the proof of knowledge of a Pedersen commitment, `e(C, -σG) · e(PoK, G) = 1`.
+pub fn verify_commitment_pok<E: Pairing>(
+    key: &GnarkPedersenVerifyingKey<E>,
+    commitment: &E::G1Affine,
+    pok: &E::G1Affine,
+) -> bool {
+    E::multi_pairing([*commitment, *pok], [key.g_sigma_neg, key.g]).is_zero()
+}
+
+/// Fold the BSB22 commitment of a gnark proof into a proof-specific verifying key, so that the
+/// proof can be checked by a plain Groth16 verifier such as Sui's.
+///
+/// The proof of knowledge of the commitment is verified here, off-chain: a plain Groth16 verifier
+/// cannot check it, so the folded key must not be trusted on its own. `public_inputs` are the
+/// public inputs of the circuit, without the commitment hash. Circuits with more than one
+/// commitment are rejected with [`CommitmentError::Unsupported`].
+pub fn fold_bsb22_commitment<E: GnarkCurve>(
+    vk: &GnarkGroth16VerifyingKey<E>,
+    proof: &GnarkGroth16Proof<E>,
+    public_inputs: &[E::ScalarField],
+) -> Result<FoldedGroth16<E>, CommitmentError> {
+    let num_commitments = vk.public_and_commitment_committed.len();
+    if num_commitments > 1 {
+        return Err(CommitmentError::Unsupported(num_commitments));
+    }
+    if proof.commitments.len() != num_commitments {
+        return Err(CommitmentError::CountMismatch {
+            expected: num_commitments,
+            actual: proof.commitments.len(),
+        });
+    }
+
+    let mut verifying_key: VerifyingKey<E> = vk.clone().into();
+    let mut public_inputs = public_inputs.to_vec();
+    let (Some(committed), Some(commitment)) = (
+        vk.public_and_commitment_committed.first(),
+        proof.commitments.first(),
+    ) else {
+        return Ok(FoldedGroth16 {
+            verifying_key,
+            public_inputs,
+        });
+    };
+    let [key] = vk.commitment_keys.as_slice() else {
+        return Err(CommitmentError::InvalidVerifyingKey {
+            keys: vk.commitment_keys.len(),
+            commitments: num_commitments,
+        });
+    };
+    if !verify_commitment_pok(key, commitment, &proof.commitment_pok) {
+        return Err(CommitmentError::InvalidProofOfKnowledge);
+    }
+
+    // gnark indexes the public witness from 1, as index 0 is the constant one wire.
+    let committed_public_inputs = committed
+        .iter()
+        .map(|&index| {
+            index
+                .checked_sub(1)
+                .and_then(|index| public_inputs.get(index as usize))
+                .copied()
+                .ok_or(CommitmentError::InvalidCommittedIndex(index))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    public_inputs.push(commitment_hash::<E>(commitment, &committed_public_inputs));
+
+    if let Some(k_0) = verifying_key.gamma_abc_g1.first_mut() {
+        *k_0 = (k_0.into_group() + commitment).into_affine();
+    }
+
+    Ok(FoldedGroth16 {
+        verifying_key,
+        public_inputs,
+    })
+}
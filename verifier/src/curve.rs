@@ -0,0 +1,312 @@
+use std::fmt;
+use std::str::FromStr;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::pairing::Pairing;
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::ark_converter::{
+    ArkGroth16Error, GNARK_MASK, ValidationMode, ark_decompressed_g1_to_gnark_decompressed_g1,
+    ark_decompressed_g2_to_gnark_decompressed_g2, compress_g1, compress_g2,
+    decompress_g1_with_mode, decompress_g2_with_mode, gnark_decompressed_g1_to_ark_decompressed_g1,
+    gnark_decompressed_g2_to_ark_decompressed_g2, is_canonical_fq,
+};
+
+/// A curve supported by the `sui::groth16` module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SuiCurve {
+    /// `groth16::bn254()`, the curve of SP1 proofs.
+    Bn254,
+    /// `groth16::bls12381()`.
+    Bls12381,
+}
+
+/// Error returned when parsing an unknown curve name.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unknown curve {0}, supported curves are bn254 and bls12381")]
+pub struct ParseSuiCurveError(pub String);
+
+impl SuiCurve {
+    /// The name of the `sui::groth16` function returning the curve.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Bn254 => "bn254",
+            Self::Bls12381 => "bls12381",
+        }
+    }
+}
+
+impl fmt::Display for SuiCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for SuiCurve {
+    type Err = ParseSuiCurveError;
+
+    /// Parse a curve name, `bn254` or `bls12381`. Case and dashes are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace(['-', '_'], "").as_str() {
+            "bn254" => Ok(Self::Bn254),
+            "bls12381" => Ok(Self::Bls12381),
+            _ => Err(ParseSuiCurveError(s.to_string())),
+        }
+    }
+}
+
+/// A pairing of `sui::groth16`, together with the gnark encoding of its points.
+///
+/// gnark writes verifying keys with compressed points and proofs with `WriteRawTo` with
+/// uncompressed points, in both cases big endian with flags in the most significant bits.
+/// Uncompressed points are twice the size of compressed points, and G2 points twice the size of
+/// G1 points.
+pub trait GnarkCurve: Pairing {
+    /// The curve, as passed to the `sui::groth16` functions.
+    const SUI_CURVE: SuiCurve;
+    /// Size of a compressed G1 point.
+    const G1_SIZE: usize;
+
+    fn g1_from_gnark_compressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G1Affine, ArkGroth16Error>;
+
+    fn g2_from_gnark_compressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G2Affine, ArkGroth16Error>;
+
+    /// Decode an uncompressed G1 point, always checking curve and subgroup membership.
+    fn g1_from_gnark_uncompressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G1Affine, ArkGroth16Error>;
+
+    /// Decode an uncompressed G2 point, always checking curve and subgroup membership.
+    fn g2_from_gnark_uncompressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G2Affine, ArkGroth16Error>;
+
+    /// Whether the most significant byte of a point flags it as compressed.
+    fn is_gnark_compressed(msb: u8) -> bool;
+
+    fn g1_to_gnark_compressed(g1: &Self::G1Affine) -> Vec<u8>;
+
+    fn g2_to_gnark_compressed(g2: &Self::G2Affine) -> Vec<u8>;
+
+    fn g1_to_gnark_uncompressed(g1: &Self::G1Affine) -> Vec<u8>;
+
+    fn g2_to_gnark_uncompressed(g2: &Self::G2Affine) -> Vec<u8>;
+}
+
+/// BN254 points are converted with the flag translation and endianness helpers of
+/// [`crate::ark_converter`], as gnark and arkworks encode them differently.
+impl GnarkCurve for Bn254 {
+    const SUI_CURVE: SuiCurve = SuiCurve::Bn254;
+    const G1_SIZE: usize = 32;
+
+    fn g1_from_gnark_compressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G1Affine, ArkGroth16Error> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| ArkGroth16Error::InvalidInput)?;
+        decompress_g1_with_mode(bytes, mode)
+    }
+
+    fn g2_from_gnark_compressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G2Affine, ArkGroth16Error> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| ArkGroth16Error::InvalidInput)?;
+        decompress_g2_with_mode(bytes, mode)
+    }
+
+    fn g1_from_gnark_uncompressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G1Affine, ArkGroth16Error> {
+        if mode == ValidationMode::Strict && !is_canonical_fq(bytes) {
+            return Err(ArkGroth16Error::NonCanonicalEncoding);
+        }
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| ArkGroth16Error::InvalidInput)?;
+        gnark_decompressed_g1_to_ark_decompressed_g1(bytes)
+    }
+
+    fn g2_from_gnark_uncompressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G2Affine, ArkGroth16Error> {
+        if mode == ValidationMode::Strict && !is_canonical_fq(bytes) {
+            return Err(ArkGroth16Error::NonCanonicalEncoding);
+        }
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| ArkGroth16Error::InvalidInput)?;
+        gnark_decompressed_g2_to_ark_decompressed_g2(bytes)
+    }
+
+    fn is_gnark_compressed(msb: u8) -> bool {
+        msb & GNARK_MASK != 0
+    }
+
+    fn g1_to_gnark_compressed(g1: &Self::G1Affine) -> Vec<u8> {
+        compress_g1(g1).to_vec()
+    }
+
+    fn g2_to_gnark_compressed(g2: &Self::G2Affine) -> Vec<u8> {
+        compress_g2(g2).to_vec()
+    }
+
+    fn g1_to_gnark_uncompressed(g1: &Self::G1Affine) -> Vec<u8> {
+        ark_decompressed_g1_to_gnark_decompressed_g1(g1).to_vec()
+    }
+
+    fn g2_to_gnark_uncompressed(g2: &Self::G2Affine) -> Vec<u8> {
+        ark_decompressed_g2_to_gnark_decompressed_g2(g2).to_vec()
+    }
+}
+
+/// Flags of the BLS12-381 point encoding, in the three most significant bits.
+const BLS12_381_MASK: u8 = 0b111 << 5;
+const BLS12_381_COMPRESSED: u8 = 0b100 << 5;
+
+/// Check that every 48 byte big endian chunk, without the flags of the first byte, is a canonical
+/// BLS12-381 base field element.
+fn is_canonical_bls12_381_fq(be_bytes: &[u8]) -> bool {
+    let modulus = ark_bls12_381::Fq::MODULUS.to_bytes_be();
+    let mut bytes = be_bytes.to_vec();
+    if let Some(flags) = bytes.first_mut() {
+        *flags &= !BLS12_381_MASK;
+    }
+    bytes
+        .chunks_exact(48)
+        .all(|chunk| chunk < modulus.as_slice())
+}
+
+/// Deserialize a BLS12-381 point, validating it as the BN254 points are: compressed points are
+/// only checked in strict mode, uncompressed points always are, and strict mode also rejects
+/// non-canonical coordinates.
+fn deserialize_bls12_381<P: CanonicalDeserialize>(
+    bytes: &[u8],
+    size: usize,
+    compress: Compress,
+    mode: ValidationMode,
+    error: ArkGroth16Error,
+) -> Result<P, ArkGroth16Error> {
+    if bytes.len() != size {
+        return Err(ArkGroth16Error::InvalidInput);
+    }
+    if mode == ValidationMode::Strict && !is_canonical_bls12_381_fq(bytes) {
+        return Err(ArkGroth16Error::NonCanonicalEncoding);
+    }
+    let validate = if compress == Compress::Yes && mode == ValidationMode::Lenient {
+        Validate::No
+    } else {
+        Validate::Yes
+    };
+    P::deserialize_with_mode(bytes, compress, validate).map_err(|_| error)
+}
+
+/// Serialize a BLS12-381 point. Infallible for points, which never exceed their size.
+fn serialize_bls12_381<P: CanonicalSerialize>(point: &P, compress: Compress) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(point.serialized_size(compress));
+    point
+        .serialize_with_mode(&mut bytes, compress)
+        .expect("Serialization of a point is infallible");
+    bytes
+}
+
+/// gnark and arkworks both encode BLS12-381 points as in the zcash specification: big endian,
+/// with the compression, infinity and sign flags in the three most significant bits. Unlike
+/// BN254, the bytes need no flag translation or endianness conversion.
+impl GnarkCurve for Bls12_381 {
+    const SUI_CURVE: SuiCurve = SuiCurve::Bls12381;
+    const G1_SIZE: usize = 48;
+
+    fn g1_from_gnark_compressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G1Affine, ArkGroth16Error> {
+        deserialize_bls12_381(
+            bytes,
+            Self::G1_SIZE,
+            Compress::Yes,
+            mode,
+            ArkGroth16Error::G1CompressionError,
+        )
+    }
+
+    fn g2_from_gnark_compressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G2Affine, ArkGroth16Error> {
+        deserialize_bls12_381(
+            bytes,
+            2 * Self::G1_SIZE,
+            Compress::Yes,
+            mode,
+            ArkGroth16Error::G2CompressionError,
+        )
+    }
+
+    fn g1_from_gnark_uncompressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G1Affine, ArkGroth16Error> {
+        deserialize_bls12_381(
+            bytes,
+            2 * Self::G1_SIZE,
+            Compress::No,
+            mode,
+            ArkGroth16Error::G1CompressionError,
+        )
+    }
+
+    fn g2_from_gnark_uncompressed(
+        bytes: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self::G2Affine, ArkGroth16Error> {
+        deserialize_bls12_381(
+            bytes,
+            4 * Self::G1_SIZE,
+            Compress::No,
+            mode,
+            ArkGroth16Error::G2CompressionError,
+        )
+    }
+
+    fn is_gnark_compressed(msb: u8) -> bool {
+        msb & BLS12_381_COMPRESSED != 0
+    }
+
+    fn g1_to_gnark_compressed(g1: &Self::G1Affine) -> Vec<u8> {
+        serialize_bls12_381(g1, Compress::Yes)
+    }
+
+    fn g2_to_gnark_compressed(g2: &Self::G2Affine) -> Vec<u8> {
+        serialize_bls12_381(g2, Compress::Yes)
+    }
+
+    fn g1_to_gnark_uncompressed(g1: &Self::G1Affine) -> Vec<u8> {
+        serialize_bls12_381(g1, Compress::No)
+    }
+
+    fn g2_to_gnark_uncompressed(g2: &Self::G2Affine) -> Vec<u8> {
+        serialize_bls12_381(g2, Compress::No)
+    }
+}
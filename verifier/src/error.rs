@@ -0,0 +1,66 @@
+use std::io;
+use std::path::PathBuf;
+
+use ark_serialize::SerializationError;
+use thiserror::Error;
+
+use crate::ark_converter::ArkGroth16Error;
+use crate::commitment::CommitmentError;
+#[cfg(feature = "serde")]
+use crate::snarkjs::SnarkjsError;
+
+/// Errors returned when converting an SP1 Groth16 proof into the Sui format.
+#[derive(Error, Debug)]
+pub enum ConvertError {
+    #[error("SP1 proof is not a Groth16 proof")]
+    NotGroth16Proof,
+    #[error("SP1 proof is a mock proof, convert it with `convert_mock` or the `--allow-mock` flag")]
+    MockProof,
+    #[error("SP1 proof is not a mock proof")]
+    NotMockProof,
+    #[error("Invalid vkey hash: {0}")]
+    InvalidVkeyHash(String),
+    #[error("Invalid committed values digest: {0}")]
+    InvalidCommittedValuesDigest(String),
+    #[error("Invalid public input {index}: {value}")]
+    InvalidPublicInput { index: usize, value: String },
+    #[error("Verifying key expects {expected} public inputs, got {actual}")]
+    PublicInputCountMismatch { expected: usize, actual: usize },
+    #[error("Public values do not match the committed values digest")]
+    PublicValuesMismatch,
+    #[error("Invalid proof: {0}")]
+    InvalidProof(#[source] ArkGroth16Error),
+    #[error("Invalid verifying key: {0}")]
+    InvalidVerifyingKey(#[source] ArkGroth16Error),
+    #[error("Unsupported SP1 version: no Groth16 verifying key matches prefix 0x{}", hex::encode(.0))]
+    UnsupportedSp1Version([u8; 4]),
+    #[error(
+        "Groth16 verifying key does not match the proof: expected prefix 0x{}, got 0x{}",
+        hex::encode(.expected),
+        hex::encode(.prefix)
+    )]
+    VerifyingKeyMismatch { expected: [u8; 4], prefix: [u8; 4] },
+    #[error("Failed to read Groth16 verifying key {}: {source}", .path.display())]
+    VerifyingKeyIo {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("Invalid public witness: {0}")]
+    InvalidPublicWitness(#[source] ArkGroth16Error),
+    #[error("Failed to read {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[cfg(feature = "serde")]
+    #[error("{0}")]
+    Snarkjs(#[from] SnarkjsError),
+    #[error("{0}")]
+    Commitment(#[from] CommitmentError),
+    #[error("Groth16 proof failed self-verification")]
+    VerificationFailed,
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] SerializationError),
+}
@@ -0,0 +1,103 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    ConvertError, SuiGroth16Artifacts, convert_mock, convert_raw, convert_raw_with_embedded_vk,
+};
+
+/// Errors returned when reading an [`Sp1EvmFixture`].
+#[derive(Error, Debug)]
+pub enum EvmFixtureError {
+    #[error("Failed to read EVM proof fixture {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("Invalid EVM proof fixture JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// The proof fixture written for Solidity tests by the `evm` script of the SP1 project template,
+/// such as `groth16-fixture.json`.
+///
+/// Fields of the fixture besides `vkey`, `publicValues` and `proof`, such as the program outputs,
+/// are ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Sp1EvmFixture {
+    /// Program vkey hash, as returned by `SP1VerifyingKey::bytes32`.
+    pub vkey: String,
+    /// Public values of the proof.
+    #[serde(with = "prefixed_hex")]
+    pub public_values: Vec<u8>,
+    /// SP1 proof bytes, as returned by `SP1ProofWithPublicValues::bytes`.
+    #[serde(with = "prefixed_hex")]
+    pub proof: Vec<u8>,
+}
+
+/// `0x` prefixed hex encoding, as written by the `evm` script. The prefix is optional when
+/// decoding.
+mod prefixed_hex {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        hex::decode(value.strip_prefix("0x").unwrap_or(&value)).map_err(D::Error::custom)
+    }
+}
+
+impl Sp1EvmFixture {
+    /// Decode a fixture from JSON.
+    pub fn from_json(json: &str) -> Result<Self, EvmFixtureError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Encode the fixture as pretty printed JSON, as the `evm` script does.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("JSON encoding of a fixture is infallible")
+    }
+
+    /// Load a fixture file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, EvmFixtureError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|source| EvmFixtureError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_json(&json)
+    }
+
+    /// Convert the proof of the fixture with the embedded Groth16 verifying key matching its
+    /// prefix.
+    ///
+    /// See [`convert_raw_with_embedded_vk`].
+    pub fn convert(&self) -> Result<SuiGroth16Artifacts, ConvertError> {
+        convert_raw_with_embedded_vk(&self.proof, &self.public_values, &self.vkey)
+    }
+
+    /// Convert the proof of the fixture with the given gnark Groth16 verifying key.
+    ///
+    /// See [`convert_raw`].
+    pub fn convert_with_groth16_vk(
+        &self,
+        groth16_vk: &[u8],
+    ) -> Result<SuiGroth16Artifacts, ConvertError> {
+        convert_raw(&self.proof, &self.public_values, &self.vkey, groth16_vk)
+    }
+
+    /// Convert the proof of a fixture written from a mock proof to mock artifacts.
+    ///
+    /// See [`convert_mock`].
+    pub fn convert_mock(&self) -> Result<SuiGroth16Artifacts, ConvertError> {
+        convert_mock(&self.proof, &self.public_values, &self.vkey)
+    }
+}
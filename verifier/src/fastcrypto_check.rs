@@ -0,0 +1,121 @@
+//! Compatibility check against fastcrypto-zkp, the implementation behind the `sui::groth16`
+//! natives.
+//!
+//! The converter serializes with arkworks 0.5, while fastcrypto-zkp deserializes with arkworks
+//! 0.4. Running the artifacts through fastcrypto-zkp catches any encoding drift locally instead of
+//! as an on-chain abort.
+
+use ark_serialize::SerializationError;
+use fastcrypto::error::FastCryptoError;
+use fastcrypto_zkp::bn254::api::{prepare_pvk_bytes, verify_groth16_in_bytes};
+use thiserror::Error;
+
+use crate::{SuiGroth16Artifacts, SuiPreparedVerifyingKey};
+
+/// Errors returned when checking Sui Groth16 artifacts with fastcrypto-zkp.
+///
+/// [`FastcryptoCheckError::PrepareVerifyingKey`] and [`FastcryptoCheckError::Verify`] correspond
+/// to aborts of `groth16::prepare_verifying_key` and `groth16::verify_groth16_proof` on-chain.
+#[derive(Error, Debug)]
+pub enum FastcryptoCheckError {
+    #[error("fastcrypto-zkp rejected the verifying key: {0}")]
+    PrepareVerifyingKey(#[source] FastCryptoError),
+    #[error("fastcrypto-zkp rejected the public inputs or proof points: {0}")]
+    Verify(#[source] FastCryptoError),
+    #[error("arkworks 0.5 rejected a verifying key accepted by fastcrypto-zkp: {0}")]
+    ArkworksVerifyingKey(#[source] SerializationError),
+    #[error("Prepared verifying key differs from fastcrypto-zkp in {0}")]
+    PreparedVerifyingKeyMismatch(&'static str),
+    #[error("Mock artifacts have no verifying key or proof points to verify")]
+    MockProof,
+    #[error("Groth16 proof failed fastcrypto-zkp verification")]
+    VerificationFailed,
+}
+
+/// Prepare a compressed arkworks verifying key with fastcrypto-zkp, as
+/// `groth16::prepare_verifying_key` does on-chain.
+pub fn fastcrypto_prepare_verifying_key(
+    vk_bytes: &[u8],
+) -> Result<SuiPreparedVerifyingKey, FastcryptoCheckError> {
+    let pvk = prepare_pvk_bytes(vk_bytes).map_err(FastcryptoCheckError::PrepareVerifyingKey)?;
+    let [
+        vk_gamma_abc_g1,
+        alpha_g1_beta_g2,
+        gamma_g2_neg_pc,
+        delta_g2_neg_pc,
+    ] = <[Vec<u8>; 4]>::try_from(pvk).expect("fastcrypto-zkp prepares four components");
+    Ok(SuiPreparedVerifyingKey {
+        vk_gamma_abc_g1,
+        alpha_g1_beta_g2,
+        gamma_g2_neg_pc,
+        delta_g2_neg_pc,
+    })
+}
+
+/// Verify the three byte arrays passed to `verify_groth16_bn254_proof` with fastcrypto-zkp.
+///
+/// The verifying key is prepared with `prepare_pvk_bytes` and the proof is verified with
+/// `verify_groth16_in_bytes`, the functions backing the Sui natives. The prepared key must also
+/// match [`SuiPreparedVerifyingKey::from_sui_vk_bytes`] byte for byte, so that keys prepared
+/// off-chain are accepted by `groth16::pvk_from_bytes`. Returns the prepared key.
+pub fn verify_with_fastcrypto(
+    vk_bytes: &[u8],
+    inputs_bytes: &[u8],
+    proof_bytes: &[u8],
+) -> Result<SuiPreparedVerifyingKey, FastcryptoCheckError> {
+    let pvk = fastcrypto_prepare_verifying_key(vk_bytes)?;
+    let expected = SuiPreparedVerifyingKey::from_sui_vk_bytes(vk_bytes)
+        .map_err(FastcryptoCheckError::ArkworksVerifyingKey)?;
+    for (name, actual, expected) in [
+        (
+            "vk_gamma_abc_g1",
+            &pvk.vk_gamma_abc_g1,
+            &expected.vk_gamma_abc_g1,
+        ),
+        (
+            "alpha_g1_beta_g2",
+            &pvk.alpha_g1_beta_g2,
+            &expected.alpha_g1_beta_g2,
+        ),
+        (
+            "gamma_g2_neg_pc",
+            &pvk.gamma_g2_neg_pc,
+            &expected.gamma_g2_neg_pc,
+        ),
+        (
+            "delta_g2_neg_pc",
+            &pvk.delta_g2_neg_pc,
+            &expected.delta_g2_neg_pc,
+        ),
+    ] {
+        if actual != expected {
+            return Err(FastcryptoCheckError::PreparedVerifyingKeyMismatch(name));
+        }
+    }
+
+    let verified = verify_groth16_in_bytes(
+        &pvk.vk_gamma_abc_g1,
+        &pvk.alpha_g1_beta_g2,
+        &pvk.gamma_g2_neg_pc,
+        &pvk.delta_g2_neg_pc,
+        inputs_bytes,
+        proof_bytes,
+    )
+    .map_err(FastcryptoCheckError::Verify)?;
+    if !verified {
+        return Err(FastcryptoCheckError::VerificationFailed);
+    }
+    Ok(pvk)
+}
+
+impl SuiGroth16Artifacts {
+    /// Verify the artifacts with fastcrypto-zkp, the code Sui validators run.
+    ///
+    /// See [`verify_with_fastcrypto`].
+    pub fn verify_with_fastcrypto(&self) -> Result<SuiPreparedVerifyingKey, FastcryptoCheckError> {
+        if self.mock {
+            return Err(FastcryptoCheckError::MockProof);
+        }
+        verify_with_fastcrypto(&self.verifying_key, &self.public_inputs, &self.proof_points)
+    }
+}
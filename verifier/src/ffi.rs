@@ -0,0 +1,325 @@
+//! C ABI for the converter, for services written in languages such as Go or Python.
+//!
+//! The shared library is built by the `sp1-sui-bindings` crate and declared in
+//! `include/sp1_sui.h`, which the `ffi` tests check against the generated header. Every function
+//! returns an [`Sp1SuiStatus`]; on failure, [`sp1_sui_last_error_message`] describes the error.
+//! Artifacts returned by the converter are owned by the caller and must be released with
+//! [`sp1_sui_artifacts_free`].
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
+use std::{ptr, slice};
+
+use crate::{
+    CommitmentError, ConvertError, SuiGroth16Artifacts, convert_mock, convert_raw_with_embedded_vk,
+};
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Status codes returned by the C API.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sp1SuiStatus {
+    Ok = 0,
+    /// A pointer argument is null or a string is not valid UTF-8.
+    InvalidArgument = 1,
+    /// The SP1 proof file could not be read.
+    InvalidProofFile = 2,
+    NotGroth16Proof = 3,
+    InvalidVkeyHash = 4,
+    InvalidCommittedValuesDigest = 5,
+    PublicValuesMismatch = 6,
+    InvalidProof = 7,
+    InvalidVerifyingKey = 8,
+    UnsupportedSp1Version = 9,
+    VerifyingKeyMismatch = 10,
+    VerificationFailed = 11,
+    SerializationError = 12,
+    /// A Groth16 verifying key file could not be read.
+    VerifyingKeyIoError = 13,
+    InvalidPublicInput = 14,
+    PublicInputCountMismatch = 15,
+    UnsupportedCommitments = 16,
+    InvalidCommitment = 17,
+    InvalidPublicWitness = 18,
+    /// A gnark or snarkjs verifying key, proof or public input file could not be read.
+    IoError = 19,
+    /// A snarkjs verifying key, proof or public signals file is malformed.
+    InvalidSnarkjs = 20,
+    /// A proof passed as a mock proof has proof data.
+    NotMockProof = 21,
+    /// A mock proof was passed to a converter of real proofs, see [`sp1_sui_convert_mock`].
+    MockProof = 22,
+}
+
+impl From<&ConvertError> for Sp1SuiStatus {
+    fn from(error: &ConvertError) -> Self {
+        match error {
+            ConvertError::NotGroth16Proof => Self::NotGroth16Proof,
+            ConvertError::NotMockProof => Self::NotMockProof,
+            ConvertError::MockProof => Self::MockProof,
+            ConvertError::InvalidVkeyHash(_) => Self::InvalidVkeyHash,
+            ConvertError::InvalidCommittedValuesDigest(_) => Self::InvalidCommittedValuesDigest,
+            ConvertError::InvalidPublicInput { .. } => Self::InvalidPublicInput,
+            ConvertError::PublicInputCountMismatch { .. } => Self::PublicInputCountMismatch,
+            ConvertError::PublicValuesMismatch => Self::PublicValuesMismatch,
+            ConvertError::InvalidProof(_) => Self::InvalidProof,
+            ConvertError::InvalidVerifyingKey(_) => Self::InvalidVerifyingKey,
+            ConvertError::UnsupportedSp1Version(_) => Self::UnsupportedSp1Version,
+            ConvertError::VerifyingKeyMismatch { .. } => Self::VerifyingKeyMismatch,
+            ConvertError::VerifyingKeyIo { .. } => Self::VerifyingKeyIoError,
+            ConvertError::InvalidPublicWitness(_) => Self::InvalidPublicWitness,
+            ConvertError::Io { .. } => Self::IoError,
+            #[cfg(feature = "serde")]
+            ConvertError::Snarkjs(_) => Self::InvalidSnarkjs,
+            ConvertError::Commitment(CommitmentError::Unsupported(_)) => {
+                Self::UnsupportedCommitments
+            }
+            ConvertError::Commitment(_) => Self::InvalidCommitment,
+            ConvertError::VerificationFailed => Self::VerificationFailed,
+            ConvertError::Serialization(_) => Self::SerializationError,
+        }
+    }
+}
+
+/// A byte buffer owned by the library.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Sp1SuiBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl Sp1SuiBuffer {
+    fn empty() -> Self {
+        Self {
+            data: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        let bytes = bytes.into_boxed_slice();
+        let len = bytes.len();
+        Self {
+            data: Box::into_raw(bytes).cast(),
+            len,
+        }
+    }
+
+    /// Release the buffer, which must have been allocated by [`Sp1SuiBuffer::from_vec`].
+    unsafe fn free(&mut self) {
+        if !self.data.is_null() {
+            drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(self.data, self.len)) });
+        }
+        *self = Self::empty();
+    }
+}
+
+/// The arguments of the `verify_groth16_bn254_proof` Move function.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Sp1SuiArtifacts {
+    /// Compressed arkworks Groth16 verifying key.
+    pub verifying_key: Sp1SuiBuffer,
+    /// Concatenated 32 byte little endian public inputs.
+    pub public_inputs: Sp1SuiBuffer,
+    /// Compressed arkworks Groth16 proof points.
+    pub proof_points: Sp1SuiBuffer,
+}
+
+impl From<SuiGroth16Artifacts> for Sp1SuiArtifacts {
+    fn from(artifacts: SuiGroth16Artifacts) -> Self {
+        Self {
+            verifying_key: Sp1SuiBuffer::from_vec(artifacts.verifying_key),
+            public_inputs: Sp1SuiBuffer::from_vec(artifacts.public_inputs),
+            proof_points: Sp1SuiBuffer::from_vec(artifacts.proof_points),
+        }
+    }
+}
+
+fn set_last_error(message: String) {
+    // Interior nul bytes cannot be represented in a C string.
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+/// Write the conversion result to `out` and return its status.
+unsafe fn write_result(
+    result: Result<SuiGroth16Artifacts, ConvertError>,
+    out: *mut Sp1SuiArtifacts,
+) -> Sp1SuiStatus {
+    match result {
+        Ok(artifacts) => {
+            unsafe { out.write(artifacts.into()) };
+            LAST_ERROR.with(|last_error| *last_error.borrow_mut() = None);
+            Sp1SuiStatus::Ok
+        }
+        Err(error) => {
+            set_last_error(error.to_string());
+            Sp1SuiStatus::from(&error)
+        }
+    }
+}
+
+/// Read a byte slice argument, allowing a null pointer for an empty slice.
+unsafe fn bytes_arg<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    match (data.is_null(), len) {
+        (true, 0) => Some(&[]),
+        (true, _) => None,
+        (false, _) => Some(unsafe { slice::from_raw_parts(data, len) }),
+    }
+}
+
+/// Read a UTF-8 string argument.
+unsafe fn str_arg<'a>(value: *const c_char) -> Option<&'a str> {
+    if value.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(value) }.to_str().ok()
+}
+
+fn invalid_argument(name: &str) -> Sp1SuiStatus {
+    set_last_error(format!("Invalid argument: {name}"));
+    Sp1SuiStatus::InvalidArgument
+}
+
+/// Convert SP1 Groth16 proof bytes, as returned by `SP1ProofWithPublicValues::bytes`, to the
+/// arguments of the Sui Groth16 verifier.
+///
+/// The Groth16 verifying key is selected from the embedded keys using the prefix of the proof.
+/// `vkey_hash` is the program vkey hash as returned by `SP1VerifyingKey::bytes32`. On success,
+/// the artifacts are written to `out` and must be released with [`sp1_sui_artifacts_free`].
+/// Proofs of the mock prover are rejected with [`Sp1SuiStatus::MockProof`]; convert them
+/// with [`sp1_sui_convert_mock`].
+///
+/// # Safety
+///
+/// `proof_bytes` and `public_values` must point to `proof_len` and `public_values_len` readable
+/// bytes, `vkey_hash` must be a nul terminated string and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp1_sui_convert(
+    proof_bytes: *const u8,
+    proof_len: usize,
+    public_values: *const u8,
+    public_values_len: usize,
+    vkey_hash: *const c_char,
+    out: *mut Sp1SuiArtifacts,
+) -> Sp1SuiStatus {
+    let Some(proof_bytes) = (unsafe { bytes_arg(proof_bytes, proof_len) }) else {
+        return invalid_argument("proof_bytes");
+    };
+    let Some(public_values) = (unsafe { bytes_arg(public_values, public_values_len) }) else {
+        return invalid_argument("public_values");
+    };
+    let Some(vkey_hash) = (unsafe { str_arg(vkey_hash) }) else {
+        return invalid_argument("vkey_hash");
+    };
+    if out.is_null() {
+        return invalid_argument("out");
+    }
+
+    let result = convert_raw_with_embedded_vk(proof_bytes, public_values, vkey_hash);
+    unsafe { write_result(result, out) }
+}
+
+/// Convert the proof bytes of the SP1 mock prover to mock artifacts, which carry the public inputs
+/// but an empty verifying key and empty proof points.
+///
+/// Returns [`Sp1SuiStatus::NotMockProof`] if the proof bytes are not a mock proof. On success,
+/// the artifacts are written to `out` and must be released with [`sp1_sui_artifacts_free`].
+///
+/// # Safety
+///
+/// See [`sp1_sui_convert`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp1_sui_convert_mock(
+    proof_bytes: *const u8,
+    proof_len: usize,
+    public_values: *const u8,
+    public_values_len: usize,
+    vkey_hash: *const c_char,
+    out: *mut Sp1SuiArtifacts,
+) -> Sp1SuiStatus {
+    let Some(proof_bytes) = (unsafe { bytes_arg(proof_bytes, proof_len) }) else {
+        return invalid_argument("proof_bytes");
+    };
+    let Some(public_values) = (unsafe { bytes_arg(public_values, public_values_len) }) else {
+        return invalid_argument("public_values");
+    };
+    let Some(vkey_hash) = (unsafe { str_arg(vkey_hash) }) else {
+        return invalid_argument("vkey_hash");
+    };
+    if out.is_null() {
+        return invalid_argument("out");
+    }
+
+    let result = convert_mock(proof_bytes, public_values, vkey_hash);
+    unsafe { write_result(result, out) }
+}
+
+/// Convert a serialized `SP1ProofWithPublicValues` file to the arguments of the Sui Groth16
+/// verifier. Requires the `sp1-sdk` feature.
+///
+/// On success, the artifacts are written to `out` and must be released with
+/// [`sp1_sui_artifacts_free`].
+///
+/// # Safety
+///
+/// `proof_path` must be a nul terminated string and `out` must be valid for writes.
+#[cfg(feature = "sp1-sdk")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp1_sui_convert_proof_file(
+    proof_path: *const c_char,
+    out: *mut Sp1SuiArtifacts,
+) -> Sp1SuiStatus {
+    let Some(proof_path) = (unsafe { str_arg(proof_path) }) else {
+        return invalid_argument("proof_path");
+    };
+    if out.is_null() {
+        return invalid_argument("out");
+    }
+
+    let sp1_proof_with_public_values = match sp1_sdk::SP1ProofWithPublicValues::load(proof_path) {
+        Ok(proof) => proof,
+        Err(error) => {
+            set_last_error(format!("Invalid proof file {proof_path}: {error}"));
+            return Sp1SuiStatus::InvalidProofFile;
+        }
+    };
+    unsafe { write_result(crate::try_convert(&sp1_proof_with_public_values), out) }
+}
+
+/// Release the buffers of artifacts returned by the converter.
+///
+/// The buffers are reset to null, so releasing the same artifacts twice is harmless.
+///
+/// # Safety
+///
+/// `artifacts` must be null or point to artifacts written by the converter.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn sp1_sui_artifacts_free(artifacts: *mut Sp1SuiArtifacts) {
+    let Some(artifacts) = (unsafe { artifacts.as_mut() }) else {
+        return;
+    };
+    unsafe {
+        artifacts.verifying_key.free();
+        artifacts.public_inputs.free();
+        artifacts.proof_points.free();
+    }
+}
+
+/// The message of the last error on the calling thread, or null if the last call succeeded.
+///
+/// The string is owned by the library and valid until the next call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn sp1_sui_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
@@ -0,0 +1,341 @@
+use ark_bn254::Bn254;
+use ark_ec::AffineRepr;
+use ark_ec::pairing::Pairing;
+use ark_groth16::{Proof, VerifyingKey};
+
+use crate::ark_converter::{ArkGroth16Error, GnarkReader, ValidationMode};
+use crate::curve::GnarkCurve;
+
+/// A Pedersen commitment verifying key, as encoded by gnark.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GnarkPedersenVerifyingKey<E: Pairing = Bn254> {
+    pub g: E::G2Affine,
+    pub g_sigma_neg: E::G2Affine,
+}
+
+/// Layout of the commitment keys section, which depends on the gnark version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GnarkCommitmentKeyLayout {
+    /// A single commitment key without a length prefix, written by older gnark versions.
+    Single,
+    /// A length prefixed list of commitment keys.
+    List,
+}
+
+/// A Groth16 verifying key, as encoded by gnark, over BN254 unless stated otherwise.
+///
+/// Unlike [`VerifyingKey`], this keeps every field of the gnark binary layout, so that a key can
+/// be parsed and serialized back byte for byte.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GnarkGroth16VerifyingKey<E: Pairing = Bn254> {
+    pub g1_alpha: E::G1Affine,
+    pub g1_beta: E::G1Affine,
+    pub g2_beta: E::G2Affine,
+    pub g2_gamma: E::G2Affine,
+    pub g1_delta: E::G1Affine,
+    pub g2_delta: E::G2Affine,
+    pub g1_k: Vec<E::G1Affine>,
+    /// Indices of the public inputs committed to by each commitment.
+    pub public_and_commitment_committed: Vec<Vec<u64>>,
+    pub commitment_keys: Vec<GnarkPedersenVerifyingKey<E>>,
+    pub commitment_key_layout: GnarkCommitmentKeyLayout,
+}
+
+impl<E: GnarkCurve> GnarkGroth16VerifyingKey<E> {
+    /// Parse a verifying key from its gnark binary encoding.
+    ///
+    /// Every field is bounds checked, and the buffer must not contain trailing bytes.
+    pub fn from_bytes(buffer: &[u8]) -> Result<Self, ArkGroth16Error> {
+        Self::from_bytes_with_mode(buffer, ValidationMode::Lenient)
+    }
+
+    /// Parse a verifying key from its gnark binary encoding, validating every point according
+    /// to `mode`.
+    pub fn from_bytes_with_mode(
+        buffer: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self, ArkGroth16Error> {
+        let mut reader = GnarkReader::new(buffer, mode);
+
+        let g1_alpha = reader.read_g1::<E>("G1.Alpha")?;
+        let g1_beta = reader.read_g1::<E>("G1.Beta")?;
+        let g2_beta = reader.read_g2::<E>("G2.Beta")?;
+        let g2_gamma = reader.read_g2::<E>("G2.Gamma")?;
+        let g1_delta = reader.read_g1::<E>("G1.Delta")?;
+        let g2_delta = reader.read_g2::<E>("G2.Delta")?;
+
+        let num_k = reader.read_len("len(K)", E::G1_SIZE)?;
+        let mut g1_k = Vec::with_capacity(num_k);
+        for i in 0..num_k {
+            g1_k.push(reader.read_g1::<E>(&format!("K[{i}]"))?);
+        }
+
+        // gnark encodes the committed indices as `[][]uint64`.
+        let num_committed = reader.read_len("len(PublicAndCommitmentCommitted)", 4)?;
+        let mut public_and_commitment_committed = Vec::with_capacity(num_committed);
+        for i in 0..num_committed {
+            let field = format!("PublicAndCommitmentCommitted[{i}]");
+            let num = reader.read_len(&format!("len({field})"), 8)?;
+            let mut committed = Vec::with_capacity(num);
+            for j in 0..num {
+                committed.push(reader.read_u64(&format!("{field}[{j}]"))?);
+            }
+            public_and_commitment_committed.push(committed);
+        }
+
+        // A commitment key is two compressed G2 points.
+        let commitment_key_size = 4 * E::G1_SIZE;
+        let (commitment_key_layout, num_commitment_keys) =
+            if reader.remaining() == commitment_key_size {
+                (GnarkCommitmentKeyLayout::Single, 1)
+            } else {
+                let num = reader.read_len("len(CommitmentKeys)", commitment_key_size)?;
+                (GnarkCommitmentKeyLayout::List, num)
+            };
+        let mut commitment_keys = Vec::with_capacity(num_commitment_keys);
+        for i in 0..num_commitment_keys {
+            commitment_keys.push(GnarkPedersenVerifyingKey {
+                g: reader.read_g2::<E>(&format!("CommitmentKeys[{i}].G"))?,
+                g_sigma_neg: reader.read_g2::<E>(&format!("CommitmentKeys[{i}].GSigmaNeg"))?,
+            });
+        }
+        reader.finish()?;
+
+        Ok(Self {
+            g1_alpha,
+            g1_beta,
+            g2_beta,
+            g2_gamma,
+            g1_delta,
+            g2_delta,
+            g1_k,
+            public_and_commitment_committed,
+            commitment_keys,
+            commitment_key_layout,
+        })
+    }
+
+    /// Serialize the verifying key in the gnark binary encoding.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&E::g1_to_gnark_compressed(&self.g1_alpha));
+        buffer.extend_from_slice(&E::g1_to_gnark_compressed(&self.g1_beta));
+        buffer.extend_from_slice(&E::g2_to_gnark_compressed(&self.g2_beta));
+        buffer.extend_from_slice(&E::g2_to_gnark_compressed(&self.g2_gamma));
+        buffer.extend_from_slice(&E::g1_to_gnark_compressed(&self.g1_delta));
+        buffer.extend_from_slice(&E::g2_to_gnark_compressed(&self.g2_delta));
+
+        buffer.extend_from_slice(&(self.g1_k.len() as u32).to_be_bytes());
+        for k in &self.g1_k {
+            buffer.extend_from_slice(&E::g1_to_gnark_compressed(k));
+        }
+
+        let num_committed = self.public_and_commitment_committed.len() as u32;
+        buffer.extend_from_slice(&num_committed.to_be_bytes());
+        for committed in &self.public_and_commitment_committed {
+            buffer.extend_from_slice(&(committed.len() as u32).to_be_bytes());
+            for index in committed {
+                buffer.extend_from_slice(&index.to_be_bytes());
+            }
+        }
+
+        if self.commitment_key_layout == GnarkCommitmentKeyLayout::List {
+            buffer.extend_from_slice(&(self.commitment_keys.len() as u32).to_be_bytes());
+        }
+        for key in &self.commitment_keys {
+            buffer.extend_from_slice(&E::g2_to_gnark_compressed(&key.g));
+            buffer.extend_from_slice(&E::g2_to_gnark_compressed(&key.g_sigma_neg));
+        }
+        buffer
+    }
+
+    /// Whether the circuit uses Pedersen commitments, i.e. `api.Commit` in gnark.
+    pub fn has_commitments(&self) -> bool {
+        !self.public_and_commitment_committed.is_empty()
+    }
+
+    /// Number of public inputs of the circuit, not counting the hashes of its commitments.
+    pub fn num_public_inputs(&self) -> usize {
+        self.g1_k
+            .len()
+            .saturating_sub(1 + self.public_and_commitment_committed.len())
+    }
+}
+
+/// A Groth16 proof, as encoded by gnark's `Proof.WriteRawTo`, over BN254 unless stated otherwise.
+///
+/// Proofs of circuits without commitments are commonly truncated to `Ar`, `Bs` and `Krs`, 256
+/// bytes over BN254, as SP1 does. Both forms are accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GnarkGroth16Proof<E: Pairing = Bn254> {
+    pub ar: E::G1Affine,
+    pub bs: E::G2Affine,
+    pub krs: E::G1Affine,
+    /// Pedersen commitments, one per `api.Commit` call.
+    pub commitments: Vec<E::G1Affine>,
+    /// Batched proof of knowledge of the commitments.
+    pub commitment_pok: E::G1Affine,
+}
+
+impl<E: GnarkCurve> GnarkGroth16Proof<E> {
+    /// Parse a proof from its gnark raw encoding.
+    ///
+    /// Every point is checked for curve and subgroup membership, and the buffer must not contain
+    /// trailing bytes.
+    pub fn from_bytes(buffer: &[u8]) -> Result<Self, ArkGroth16Error> {
+        Self::from_bytes_with_mode(buffer, ValidationMode::Lenient)
+    }
+
+    /// Parse a proof from its gnark raw encoding. Strict mode also rejects non-canonical
+    /// coordinates.
+    pub fn from_bytes_with_mode(
+        buffer: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self, ArkGroth16Error> {
+        Self::read(buffer, mode, false)
+    }
+
+    /// Parse a proof from its gnark compressed encoding, as written by `Proof.WriteTo`, validating
+    /// every point according to `mode`.
+    pub fn from_compressed_bytes_with_mode(
+        buffer: &[u8],
+        mode: ValidationMode,
+    ) -> Result<Self, ArkGroth16Error> {
+        Self::read(buffer, mode, true)
+    }
+
+    /// Whether `buffer` starts with a compressed point, i.e. was written by `Proof.WriteTo` rather
+    /// than `Proof.WriteRawTo`.
+    pub fn is_compressed(buffer: &[u8]) -> bool {
+        buffer
+            .first()
+            .is_some_and(|&msb| E::is_gnark_compressed(msb))
+    }
+
+    fn read(
+        buffer: &[u8],
+        mode: ValidationMode,
+        compressed: bool,
+    ) -> Result<Self, ArkGroth16Error> {
+        let mut reader = GnarkReader::new(buffer, mode);
+        let read_g1 = |reader: &mut GnarkReader, field: &str| {
+            if compressed {
+                reader.read_g1::<E>(field)
+            } else {
+                reader.read_g1_uncompressed::<E>(field)
+            }
+        };
+
+        let ar = read_g1(&mut reader, "Ar")?;
+        let bs = if compressed {
+            reader.read_g2::<E>("Bs")?
+        } else {
+            reader.read_g2_uncompressed::<E>("Bs")?
+        };
+        let krs = read_g1(&mut reader, "Krs")?;
+
+        let mut commitments = Vec::new();
+        let mut commitment_pok = E::G1Affine::zero();
+        if reader.remaining() != 0 {
+            let g1_size = if compressed {
+                E::G1_SIZE
+            } else {
+                2 * E::G1_SIZE
+            };
+            let num = reader.read_len("len(Commitments)", g1_size)?;
+            for i in 0..num {
+                commitments.push(read_g1(&mut reader, &format!("Commitments[{i}]"))?);
+            }
+            commitment_pok = read_g1(&mut reader, "CommitmentPok")?;
+        }
+        reader.finish()?;
+
+        Ok(Self {
+            ar,
+            bs,
+            krs,
+            commitments,
+            commitment_pok,
+        })
+    }
+
+    /// Serialize the proof in the gnark raw encoding, truncated to `Ar`, `Bs` and `Krs` if the
+    /// proof has no commitments.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.write(E::g1_to_gnark_uncompressed, E::g2_to_gnark_uncompressed)
+    }
+
+    /// Serialize the proof in the gnark compressed encoding, truncated as [`Self::to_bytes`].
+    pub fn to_compressed_bytes(&self) -> Vec<u8> {
+        self.write(E::g1_to_gnark_compressed, E::g2_to_gnark_compressed)
+    }
+
+    fn write(
+        &self,
+        write_g1: fn(&E::G1Affine) -> Vec<u8>,
+        write_g2: fn(&E::G2Affine) -> Vec<u8>,
+    ) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&write_g1(&self.ar));
+        buffer.extend_from_slice(&write_g2(&self.bs));
+        buffer.extend_from_slice(&write_g1(&self.krs));
+        if self.has_commitments() {
+            buffer.extend_from_slice(&(self.commitments.len() as u32).to_be_bytes());
+            for commitment in &self.commitments {
+                buffer.extend_from_slice(&write_g1(commitment));
+            }
+            buffer.extend_from_slice(&write_g1(&self.commitment_pok));
+        }
+        buffer
+    }
+
+    /// Whether the proof carries Pedersen commitments.
+    pub fn has_commitments(&self) -> bool {
+        !self.commitments.is_empty()
+    }
+}
+
+impl<E: Pairing> From<&GnarkGroth16Proof<E>> for Proof<E> {
+    /// The Groth16 proof points. The commitments must be folded into the verifying key
+    /// separately, see [`crate::fold_bsb22_commitment`].
+    fn from(proof: &GnarkGroth16Proof<E>) -> Self {
+        Proof {
+            a: proof.ar,
+            b: proof.bs,
+            c: proof.krs,
+        }
+    }
+}
+
+impl<E: Pairing> From<GnarkGroth16VerifyingKey<E>> for VerifyingKey<E> {
+    fn from(vk: GnarkGroth16VerifyingKey<E>) -> Self {
+        VerifyingKey {
+            alpha_g1: vk.g1_alpha,
+            beta_g2: vk.g2_beta,
+            gamma_g2: vk.g2_gamma,
+            delta_g2: vk.g2_delta,
+            gamma_abc_g1: vk.g1_k,
+        }
+    }
+}
+
+impl<E: Pairing> From<&VerifyingKey<E>> for GnarkGroth16VerifyingKey<E> {
+    /// Convert an arkworks verifying key to the gnark model.
+    ///
+    /// arkworks does not keep `G1.Beta` and `G1.Delta`, which are not used for verification, so
+    /// they are set to the point at infinity. The key has no commitments.
+    fn from(vk: &VerifyingKey<E>) -> Self {
+        Self {
+            g1_alpha: vk.alpha_g1,
+            g1_beta: E::G1Affine::zero(),
+            g2_beta: vk.beta_g2,
+            g2_gamma: vk.gamma_g2,
+            g1_delta: E::G1Affine::zero(),
+            g2_delta: vk.delta_g2,
+            g1_k: vk.gamma_abc_g1.clone(),
+            public_and_commitment_committed: Vec::new(),
+            commitment_keys: Vec::new(),
+            commitment_key_layout: GnarkCommitmentKeyLayout::List,
+        }
+    }
+}
@@ -0,0 +1,500 @@
+use std::fmt;
+
+use ark_bn254::{Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ff::{BigInt, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::ark_converter::load_ark_groth16_verifying_key_from_bytes;
+use crate::{Sp1Version, supported_versions};
+
+/// Validity of a decoded curve point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointStatus {
+    Valid,
+    /// The point at infinity.
+    Identity,
+    /// The point is on the curve but not in the prime order subgroup.
+    NotInSubgroup,
+    /// The bytes are not a valid compressed point on the curve.
+    Invalid,
+}
+
+/// A curve point decoded from compressed arkworks bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InspectedCurvePoint {
+    G1(G1Affine),
+    G2(G2Affine),
+}
+
+/// A named curve point of Sui Groth16 artifacts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectedPoint {
+    pub name: String,
+    pub status: PointStatus,
+    /// The decoded point, unless the bytes are [`PointStatus::Invalid`].
+    pub point: Option<InspectedCurvePoint>,
+}
+
+/// A public input decoded from a 32 byte little endian scalar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectedScalar {
+    pub value: BigInt<4>,
+    /// Whether the value is smaller than the BN254 scalar field modulus.
+    pub canonical: bool,
+}
+
+impl InspectedScalar {
+    /// The value as a `0x` prefixed big endian hex string.
+    pub fn hex(&self) -> String {
+        let mut bytes = self.value.0.map(u64::to_be_bytes);
+        bytes.reverse();
+        format!("0x{}", hex::encode(bytes.concat()))
+    }
+}
+
+/// The decoded form of the three arguments of `verify_groth16_bn254_proof`.
+///
+/// Decoding never fails: malformed sections are reported in [`SuiArtifactsInspection::issues`]
+/// and invalid points are flagged instead, so that the cause of an on-chain abort can be found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuiArtifactsInspection {
+    /// `alpha_g1`, `beta_g2`, `gamma_g2`, `delta_g2` and the `gamma_abc_g1` points.
+    pub verifying_key: Vec<InspectedPoint>,
+    /// Number of `gamma_abc_g1` points declared by the verifying key.
+    pub num_gamma_abc_g1: Option<usize>,
+    pub public_inputs: Vec<InspectedScalar>,
+    /// `a`, `b` and `c`.
+    pub proof_points: Vec<InspectedPoint>,
+    /// SP1 version of the verifying key, which names the public inputs. Detected if the key is
+    /// the converted key of an embedded SP1 version.
+    pub sp1_version: Option<Sp1Version>,
+    pub issues: Vec<String>,
+}
+
+impl SuiArtifactsInspection {
+    /// Number of public inputs expected by the verifying key, `gamma_abc_g1.len() - 1`.
+    pub fn expected_public_inputs(&self) -> Option<usize> {
+        self.num_gamma_abc_g1.map(|num| num.saturating_sub(1))
+    }
+
+    /// Whether no issue was found. This does not check the pairing equation.
+    pub fn is_well_formed(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Sequential reader over a compressed arkworks buffer.
+struct Reader<'a> {
+    section: &'static str,
+    buffer: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read(&mut self, len: usize, field: &str, issues: &mut Vec<String>) -> Option<&'a [u8]> {
+        let Some(bytes) = self.buffer.get(self.offset..self.offset + len) else {
+            issues.push(format!(
+                "{}: {field} at offset {} is truncated",
+                self.section, self.offset
+            ));
+            self.offset = self.buffer.len();
+            return None;
+        };
+        self.offset += len;
+        Some(bytes)
+    }
+
+    fn read_g1(
+        &mut self,
+        name: String,
+        inspection: &mut Vec<InspectedPoint>,
+        issues: &mut Vec<String>,
+    ) -> Option<()> {
+        let bytes = self.read(32, &name, issues)?;
+        let (status, point) = inspect_point(bytes, InspectedCurvePoint::G1);
+        self.push_point(name, status, point, inspection, issues);
+        Some(())
+    }
+
+    fn read_g2(
+        &mut self,
+        name: String,
+        inspection: &mut Vec<InspectedPoint>,
+        issues: &mut Vec<String>,
+    ) -> Option<()> {
+        let bytes = self.read(64, &name, issues)?;
+        let (status, point) = inspect_point(bytes, InspectedCurvePoint::G2);
+        self.push_point(name, status, point, inspection, issues);
+        Some(())
+    }
+
+    fn push_point(
+        &self,
+        name: String,
+        status: PointStatus,
+        point: Option<InspectedCurvePoint>,
+        inspection: &mut Vec<InspectedPoint>,
+        issues: &mut Vec<String>,
+    ) {
+        let issue = match status {
+            PointStatus::Valid => None,
+            PointStatus::Identity => Some("is the point at infinity"),
+            PointStatus::NotInSubgroup => Some("is not in the subgroup"),
+            PointStatus::Invalid => Some("is not a valid point"),
+        };
+        if let Some(issue) = issue {
+            issues.push(format!("{}: {name} {issue}", self.section));
+        }
+        inspection.push(InspectedPoint {
+            name,
+            status,
+            point,
+        });
+    }
+
+    fn finish(&self, issues: &mut Vec<String>) {
+        if self.offset < self.buffer.len() {
+            issues.push(format!(
+                "{}: {} unexpected trailing bytes",
+                self.section,
+                self.buffer.len() - self.offset
+            ));
+        }
+    }
+}
+
+fn inspect_point<P: SWCurveConfig>(
+    bytes: &[u8],
+    wrap: fn(Affine<P>) -> InspectedCurvePoint,
+) -> (PointStatus, Option<InspectedCurvePoint>) {
+    let Ok(point) = Affine::<P>::deserialize_compressed_unchecked(bytes) else {
+        return (PointStatus::Invalid, None);
+    };
+    let status = if point.is_zero() {
+        PointStatus::Identity
+    } else if !point.is_in_correct_subgroup_assuming_on_curve() {
+        PointStatus::NotInSubgroup
+    } else {
+        PointStatus::Valid
+    };
+    (status, Some(wrap(point)))
+}
+
+/// Read the points of a compressed arkworks verifying key, stopping at the first truncated field.
+fn read_verifying_key(
+    reader: &mut Reader,
+    points: &mut Vec<InspectedPoint>,
+    num_gamma_abc_g1: &mut Option<usize>,
+    issues: &mut Vec<String>,
+) -> Option<()> {
+    reader.read_g1("alpha_g1".into(), points, issues)?;
+    reader.read_g2("beta_g2".into(), points, issues)?;
+    reader.read_g2("gamma_g2".into(), points, issues)?;
+    reader.read_g2("delta_g2".into(), points, issues)?;
+    let len = reader.read(8, "len(gamma_abc_g1)", issues)?;
+    let num = u64::from_le_bytes(len.try_into().unwrap()) as usize;
+    *num_gamma_abc_g1 = Some(num);
+    if num == 0 {
+        issues.push("verifying key: gamma_abc_g1 is empty".to_string());
+    }
+    for i in 0..num {
+        reader.read_g1(format!("gamma_abc_g1[{i}]"), points, issues)?;
+    }
+    reader.finish(issues);
+    Some(())
+}
+
+/// Read the points of compressed arkworks proof points, stopping at the first truncated field.
+fn read_proof_points(
+    reader: &mut Reader,
+    points: &mut Vec<InspectedPoint>,
+    issues: &mut Vec<String>,
+) -> Option<()> {
+    reader.read_g1("a".into(), points, issues)?;
+    reader.read_g2("b".into(), points, issues)?;
+    reader.read_g1("c".into(), points, issues)?;
+    reader.finish(issues);
+    Some(())
+}
+
+/// The SP1 version whose embedded Groth16 verifying key converts to the compressed arkworks key
+/// `vk_bytes`.
+fn detect_sp1_version(vk_bytes: &[u8]) -> Option<Sp1Version> {
+    supported_versions().iter().copied().find(|version| {
+        load_ark_groth16_verifying_key_from_bytes(version.groth16_vk()).is_ok_and(|vk| {
+            let mut converted = Vec::new();
+            vk.serialize_compressed(&mut converted).is_ok() && converted == vk_bytes
+        })
+    })
+}
+
+/// Decode the compressed verifying key, public inputs and proof points passed to
+/// `verify_groth16_bn254_proof`, for debugging.
+///
+/// See [`crate::verify_sui_artifacts`] to verify the proof.
+pub fn inspect_sui_artifacts(
+    vk_bytes: &[u8],
+    inputs_bytes: &[u8],
+    proof_bytes: &[u8],
+) -> SuiArtifactsInspection {
+    let mut issues = Vec::new();
+
+    let mut verifying_key = Vec::new();
+    let mut num_gamma_abc_g1 = None;
+    let mut reader = Reader {
+        section: "verifying key",
+        buffer: vk_bytes,
+        offset: 0,
+    };
+    read_verifying_key(
+        &mut reader,
+        &mut verifying_key,
+        &mut num_gamma_abc_g1,
+        &mut issues,
+    );
+
+    if !inputs_bytes.len().is_multiple_of(32) {
+        issues.push(format!(
+            "public inputs: length {} is not a multiple of 32",
+            inputs_bytes.len()
+        ));
+    }
+    let public_inputs: Vec<InspectedScalar> = inputs_bytes
+        .chunks_exact(32)
+        .map(|bytes| {
+            let value = BigInt::<4>::deserialize_compressed(bytes).expect("32 bytes");
+            InspectedScalar {
+                value,
+                canonical: value < Fr::MODULUS,
+            }
+        })
+        .collect();
+    for (i, input) in public_inputs.iter().enumerate() {
+        if !input.canonical {
+            issues.push(format!(
+                "public inputs: input {i} is not smaller than the scalar field modulus"
+            ));
+        }
+    }
+    if let Some(expected) = num_gamma_abc_g1.map(|num| num.saturating_sub(1))
+        && public_inputs.len() != expected
+    {
+        issues.push(format!(
+            "public inputs: verifying key expects {expected} public inputs, got {}",
+            public_inputs.len()
+        ));
+    }
+
+    let mut proof_points = Vec::new();
+    let mut reader = Reader {
+        section: "proof points",
+        buffer: proof_bytes,
+        offset: 0,
+    };
+    read_proof_points(&mut reader, &mut proof_points, &mut issues);
+
+    SuiArtifactsInspection {
+        verifying_key,
+        num_gamma_abc_g1,
+        public_inputs,
+        proof_points,
+        sp1_version: detect_sp1_version(vk_bytes),
+        issues,
+    }
+}
+
+impl fmt::Display for InspectedPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self.status {
+            PointStatus::Valid => "valid",
+            PointStatus::Identity => "point at infinity",
+            PointStatus::NotInSubgroup => "not in subgroup",
+            PointStatus::Invalid => "invalid encoding or not on curve",
+        };
+        writeln!(f, "  {}: {status}", self.name)?;
+        match &self.point {
+            Some(InspectedCurvePoint::G1(point)) if !point.infinity => {
+                writeln!(f, "    x: {}", point.x)?;
+                writeln!(f, "    y: {}", point.y)?;
+            }
+            Some(InspectedCurvePoint::G2(point)) if !point.infinity => {
+                writeln!(f, "    x: ({}, {})", point.x.c0, point.x.c1)?;
+                writeln!(f, "    y: ({}, {})", point.y.c0, point.y.c1)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for SuiArtifactsInspection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Verifying key:")?;
+        for point in &self.verifying_key {
+            write!(f, "{point}")?;
+        }
+        if let Some(num) = self.num_gamma_abc_g1 {
+            writeln!(f, "  IC points: {num}")?;
+        }
+
+        write!(f, "\nPublic inputs: {}", self.public_inputs.len())?;
+        match self.expected_public_inputs() {
+            Some(expected) => writeln!(f, " (verifying key expects {expected})")?,
+            None => writeln!(f)?,
+        }
+        let schema = self.sp1_version.map(Sp1Version::public_input_schema);
+        for (i, input) in self.public_inputs.iter().enumerate() {
+            match schema.and_then(|schema| schema.get(i)) {
+                Some(name) => writeln!(f, "  [{i}] {name}: {} ({})", input.value, input.hex())?,
+                None => writeln!(f, "  [{i}] {} ({})", input.value, input.hex())?,
+            }
+        }
+
+        writeln!(f, "\nProof points:")?;
+        for point in &self.proof_points {
+            write!(f, "{point}")?;
+        }
+
+        if self.issues.is_empty() {
+            writeln!(f, "\nNo issues found")
+        } else {
+            writeln!(f, "\nIssues:")?;
+            for issue in &self.issues {
+                writeln!(f, "  - {issue}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Bn254, Fq2};
+    use ark_ff::BigInteger;
+    use ark_groth16::{Proof, VerifyingKey};
+
+    use super::*;
+
+    fn compressed(value: impl CanonicalSerialize) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        value.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// A verifying key for one public input and proof points made of the curve generators.
+    fn artifacts() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let (g1, g2) = (G1Affine::generator(), G2Affine::generator());
+        let vk = VerifyingKey::<Bn254> {
+            alpha_g1: g1,
+            beta_g2: g2,
+            gamma_g2: g2,
+            delta_g2: g2,
+            gamma_abc_g1: vec![g1, g1],
+        };
+        let proof = Proof::<Bn254> {
+            a: g1,
+            b: g2,
+            c: g1,
+        };
+        let inputs = Fr::from(5u64).into_bigint().to_bytes_le();
+        (compressed(vk), inputs, compressed(proof))
+    }
+
+    #[test]
+    fn test_inspected_scalar_hex() {
+        let scalar = InspectedScalar {
+            value: BigInt::from(0x0102u64),
+            canonical: true,
+        };
+        assert_eq!(scalar.hex(), format!("0x{}0102", "0".repeat(60)));
+        let scalar = InspectedScalar {
+            value: BigInt::new([0, 0, 0, 1 << 63]),
+            canonical: false,
+        };
+        assert_eq!(scalar.hex(), format!("0x80{}", "0".repeat(62)));
+    }
+
+    #[test]
+    fn test_inspect_well_formed() {
+        let (vk, inputs, proof) = artifacts();
+        let inspection = inspect_sui_artifacts(&vk, &inputs, &proof);
+        assert!(inspection.is_well_formed(), "{inspection}");
+        assert_eq!(inspection.num_gamma_abc_g1, Some(2));
+        assert_eq!(inspection.expected_public_inputs(), Some(1));
+        assert_eq!(inspection.verifying_key.len(), 6);
+        assert_eq!(inspection.verifying_key[5].name, "gamma_abc_g1[1]");
+        assert_eq!(
+            inspection.public_inputs,
+            [InspectedScalar {
+                value: BigInt::from(5u64),
+                canonical: true,
+            }]
+        );
+        assert_eq!(
+            inspection.proof_points[1].point,
+            Some(InspectedCurvePoint::G2(G2Affine::generator()))
+        );
+        assert_eq!(inspection.sp1_version, None);
+    }
+
+    #[test]
+    fn test_inspect_malformed() {
+        let (mut vk, inputs, mut proof) = artifacts();
+
+        // A G2 point on the curve but outside of the prime order subgroup.
+        let b = (1u64..)
+            .find_map(|x| G2Affine::get_point_from_x_unchecked(Fq2::from(x), false))
+            .unwrap();
+        assert!(!b.is_in_correct_subgroup_assuming_on_curve());
+        proof[32..96].copy_from_slice(&compressed(b));
+        vk.push(0);
+        let inspection = inspect_sui_artifacts(&vk, &[], &proof);
+        assert_eq!(
+            inspection.proof_points[1].status,
+            PointStatus::NotInSubgroup
+        );
+        assert_eq!(
+            inspection.issues,
+            [
+                "verifying key: 1 unexpected trailing bytes",
+                "public inputs: verifying key expects 1 public inputs, got 0",
+                "proof points: b is not in the subgroup",
+            ]
+        );
+
+        // An empty key has no `gamma_abc_g1` points.
+        let inspection = inspect_sui_artifacts(&[], &inputs, &[]);
+        assert_eq!(inspection.num_gamma_abc_g1, None);
+        assert_eq!(inspection.expected_public_inputs(), None);
+        assert_eq!(
+            inspection.issues,
+            [
+                "verifying key: alpha_g1 at offset 0 is truncated",
+                "proof points: a at offset 0 is truncated",
+            ]
+        );
+        let mut empty_gamma_abc_g1 = vk[..32 + 3 * 64].to_vec();
+        empty_gamma_abc_g1.extend(0u64.to_le_bytes());
+        let inspection = inspect_sui_artifacts(&empty_gamma_abc_g1, &[], &proof);
+        assert_eq!(inspection.expected_public_inputs(), Some(0));
+        assert_eq!(
+            inspection.issues,
+            [
+                "verifying key: gamma_abc_g1 is empty",
+                "proof points: b is not in the subgroup",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_sp1_version() {
+        for &version in supported_versions() {
+            let vk = load_ark_groth16_verifying_key_from_bytes(version.groth16_vk()).unwrap();
+            assert_eq!(detect_sp1_version(&compressed(vk)), Some(version));
+        }
+        assert_eq!(detect_sp1_version(&artifacts().0), None);
+        assert_eq!(detect_sp1_version(&[]), None);
+    }
+}
@@ -2,92 +2,505 @@
 mod test;
 
 pub mod ark_converter;
+mod artifacts;
+#[cfg(feature = "serde")]
+mod bundle;
+mod circuit;
+mod commitment;
+mod curve;
+mod error;
+#[cfg(feature = "serde")]
+mod evm_fixture;
+#[cfg(feature = "fastcrypto")]
+mod fastcrypto_check;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod gnark;
+mod inspect;
+mod prepared;
+mod public_inputs;
+mod public_values;
+#[cfg(feature = "serde")]
+mod snarkjs;
+mod verify;
+mod version;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-/// Groth16 verification keys for different SP1 versions.
-pub const GROTH16_VK_4_0_0_RC3_BYTES: &[u8] = include_bytes!("../vk/v4.0.0-rc.3/groth16_vk.bin");
-pub const GROTH16_VK_3_0_0_BYTES: &[u8] = include_bytes!("../vk/v3.0.0/groth16_vk.bin");
-pub const GROTH16_VK_3_0_0_RC4_BYTES: &[u8] = include_bytes!("../vk/v3.0.0rc4/groth16_vk.bin");
-pub const GROTH16_VK_2_0_0_BYTES: &[u8] = include_bytes!("../vk/v2.0.0/groth16_vk.bin");
+pub use ark_converter::ValidationMode;
+pub use artifacts::SuiGroth16Artifacts;
+#[cfg(feature = "serde")]
+pub use bundle::{BundleError, SUI_PROOF_BUNDLE_MAGIC, SUI_PROOF_BUNDLE_VERSION, SuiProofBundle};
+pub use circuit::{
+    SuiCircuitArtifacts, convert_gnark_groth16, convert_gnark_groth16_files,
+    convert_gnark_groth16_with_mode, load_gnark_public_witness,
+};
+pub use commitment::{
+    BSB22_COMMITMENT_DST, CommitmentError, FoldedGroth16, bsb22_commitment_hash,
+    fold_bsb22_commitment, hash_to_field, verify_commitment_pok,
+};
+pub use curve::{GnarkCurve, ParseSuiCurveError, SuiCurve};
+pub use error::ConvertError;
+#[cfg(feature = "serde")]
+pub use evm_fixture::{EvmFixtureError, Sp1EvmFixture};
+#[cfg(feature = "fastcrypto")]
+pub use fastcrypto_check::{
+    FastcryptoCheckError, fastcrypto_prepare_verifying_key, verify_with_fastcrypto,
+};
+pub use inspect::{
+    InspectedCurvePoint, InspectedPoint, InspectedScalar, PointStatus, SuiArtifactsInspection,
+    inspect_sui_artifacts,
+};
+pub use prepared::SuiPreparedVerifyingKey;
+pub use public_inputs::{Sp1PublicInput, sp1_public_input_schema};
+pub use public_values::{hash_public_values, verify_public_values};
+#[cfg(feature = "serde")]
+pub use snarkjs::{
+    SnarkjsError, SnarkjsG1, SnarkjsG2, SnarkjsProof, SnarkjsVerifyingKey, convert_snarkjs_groth16,
+    convert_snarkjs_groth16_files, parse_snarkjs_proof, parse_snarkjs_public_signals,
+    parse_snarkjs_verifying_key,
+};
+pub use verify::{
+    SUI_MAX_PUBLIC_INPUTS, SuiVerification, SuiVerifyError, parse_sui_public_inputs,
+    verify_sui_artifacts, verify_sui_groth16,
+};
+pub use version::{GROTH16_VK_REGISTRY, ParseSp1VersionError, Sp1Version, supported_versions};
+
+// The `GROTH16_VK_<VERSION>_BYTES` constants, one per key of [`GROTH16_VK_REGISTRY`].
+include!(concat!(env!("OUT_DIR"), "/groth16_vks.rs"));
+
+use std::fs;
+use std::path::Path;
 
 use ark_bn254::Bn254;
-use ark_groth16::{Groth16, prepare_verifying_key};
+use ark_groth16::Proof;
 use ark_serialize::CanonicalSerialize;
-use ark_snark::SNARK;
+#[cfg(feature = "sp1-sdk")]
 use num_bigint::BigUint;
+#[cfg(feature = "sp1-sdk")]
 use num_traits::Num;
+use sha2::{Digest, Sha256};
+#[cfg(feature = "sp1-sdk")]
 use sp1_sdk::SP1ProofWithPublicValues;
 
 use crate::ark_converter::{
-    load_ark_groth16_verifying_key_from_bytes, load_ark_proof_from_bytes,
-    load_ark_public_inputs_from_bytes,
+    ArkGroth16Error, ark_proof_to_gnark_bytes, load_ark_groth16_verifying_key_from_bytes,
+    load_ark_public_inputs,
 };
+use crate::circuit::convert_gnark_parts;
+use crate::gnark::{GnarkGroth16Proof, GnarkGroth16VerifyingKey};
 
-pub fn convert_sp1_gnark_to_ark(
-    sp1_proof_with_public_values: SP1ProofWithPublicValues,
-) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
-    let proof_bytes = sp1_proof_with_public_values.bytes();
+/// Compute the 4 byte prefix SP1 prepends to Groth16 proofs made with the given verifying key.
+///
+/// This is the first 4 bytes of the SHA-256 hash of the gnark verifying key.
+pub fn groth16_vk_hash_prefix(groth16_vk: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(groth16_vk);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Encode an arkworks Groth16 proof as SP1 proof bytes, as returned by
+/// `SP1ProofWithPublicValues::bytes`, for the given gnark verifying key.
+///
+/// The result can be checked with `sp1_verifier::Groth16Verifier`.
+pub fn ark_proof_to_sp1_proof_bytes(proof: &Proof<Bn254>, groth16_vk: &[u8]) -> Vec<u8> {
+    [
+        groth16_vk_hash_prefix(groth16_vk).as_slice(),
+        ark_proof_to_gnark_bytes(proof).as_slice(),
+    ]
+    .concat()
+}
+
+/// Select the embedded Groth16 verifying key matching the 4 byte prefix of the SP1 proof bytes.
+///
+/// Returns the SP1 version and the gnark verifying key bytes.
+pub fn select_groth16_vk(
+    proof_bytes: &[u8],
+) -> Result<(&'static str, &'static [u8]), ConvertError> {
+    let prefix: [u8; 4] = proof_bytes
+        .get(..4)
+        .and_then(|prefix| prefix.try_into().ok())
+        .ok_or(ConvertError::InvalidProof(ArkGroth16Error::InvalidInput))?;
+    GROTH16_VK_REGISTRY
+        .iter()
+        .find(|(_, _, vk_prefix)| *vk_prefix == prefix)
+        .map(|(version, groth16_vk, _)| (version.as_str(), *groth16_vk))
+        .ok_or(ConvertError::UnsupportedSp1Version(prefix))
+}
+
+/// Load a gnark Groth16 verifying key, such as the `groth16_vk.bin` of an SP1 circuit build.
+///
+/// The key is parsed with the same validation as the embedded keys.
+pub fn load_groth16_vk(path: impl AsRef<Path>) -> Result<Vec<u8>, ConvertError> {
+    let path = path.as_ref();
+    let groth16_vk = fs::read(path).map_err(|source| ConvertError::VerifyingKeyIo {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    load_ark_groth16_verifying_key_from_bytes(&groth16_vk)
+        .map_err(ConvertError::InvalidVerifyingKey)?;
+    Ok(groth16_vk)
+}
+
+/// Check that a gnark Groth16 verifying key matches the 4 byte prefix of the SP1 proof bytes.
+///
+/// Returns the SP1 version of the key, or `custom` if it is not one of [`GROTH16_VK_REGISTRY`].
+fn check_groth16_vk(proof_bytes: &[u8], groth16_vk: &[u8]) -> Result<&'static str, ConvertError> {
+    let prefix: [u8; 4] = proof_bytes
+        .get(..4)
+        .and_then(|prefix| prefix.try_into().ok())
+        .ok_or(ConvertError::InvalidProof(ArkGroth16Error::InvalidInput))?;
+    let expected = groth16_vk_hash_prefix(groth16_vk);
+    if prefix != expected {
+        return Err(ConvertError::VerifyingKeyMismatch { expected, prefix });
+    }
+    Ok(Sp1Version::from_groth16_vk(groth16_vk).map_or("custom", Sp1Version::as_str))
+}
+
+/// Parse a decimal field element into 32 big endian bytes.
+#[cfg(feature = "sp1-sdk")]
+fn decimal_to_be_bytes32(value: &str) -> Option<[u8; 32]> {
+    let bytes = BigUint::from_str_radix(value, 10).ok()?.to_bytes_be();
+    if bytes.len() > 32 {
+        return None;
+    }
+    // Right-align the value so that leading zero bytes are preserved.
+    let mut padded = [0u8; 32];
+    padded[32 - bytes.len()..].copy_from_slice(&bytes);
+    Some(padded)
+}
+
+/// Convert an SP1 Groth16 proof to the arkworks format expected by the Sui verifier.
+///
+/// The Groth16 verifying key is selected from [`GROTH16_VK_REGISTRY`] using the 4 byte prefix of the
+/// proof, and the public values are checked against the committed values digest. Returns a
+/// [`ConvertError`] if the proof is malformed or does not verify against the verifying key.
+///
+/// Proofs of the SP1 mock prover are rejected with [`ConvertError::MockProof`]; convert them
+/// with [`try_convert_mock`].
+#[cfg(feature = "sp1-sdk")]
+pub fn try_convert(
+    sp1_proof_with_public_values: &SP1ProofWithPublicValues,
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    try_convert_with_mode(sp1_proof_with_public_values, ValidationMode::default())
+}
+
+/// Convert an SP1 Groth16 proof to the arkworks format expected by the Sui verifier, validating
+/// the verifying key and proof points according to `mode`.
+///
+/// See [`try_convert`].
+#[cfg(feature = "sp1-sdk")]
+pub fn try_convert_with_mode(
+    sp1_proof_with_public_values: &SP1ProofWithPublicValues,
+    mode: ValidationMode,
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    convert_sp1_proof(sp1_proof_with_public_values, None, mode)
+}
+
+/// Convert an SP1 Groth16 proof to the arkworks format expected by the Sui verifier, using the
+/// given gnark Groth16 verifying key instead of an embedded one.
+///
+/// This is useful for keys of SP1 versions that are not embedded, such as a freshly built
+/// circuit loaded with [`load_groth16_vk`]. The key must match the prefix of the proof. See
+/// [`try_convert`].
+#[cfg(feature = "sp1-sdk")]
+pub fn try_convert_with_groth16_vk(
+    sp1_proof_with_public_values: &SP1ProofWithPublicValues,
+    groth16_vk: &[u8],
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    convert_sp1_proof(
+        sp1_proof_with_public_values,
+        Some(groth16_vk),
+        ValidationMode::default(),
+    )
+}
+
+/// Convert an SP1 Groth16 proof, selecting an embedded verifying key if `groth16_vk` is `None`.
+#[cfg(feature = "sp1-sdk")]
+fn convert_sp1_proof(
+    sp1_proof_with_public_values: &SP1ProofWithPublicValues,
+    groth16_vk: Option<&[u8]>,
+    mode: ValidationMode,
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    let proof = sp1_proof_with_public_values
+        .proof
+        .try_as_groth_16_ref()
+        .ok_or(ConvertError::NotGroth16Proof)?;
+    let public_inputs = decode_sp1_public_inputs(&proof.public_inputs)?;
+
+    // Bind the public values of the proof to the committed values digest.
+    let public_values = sp1_proof_with_public_values.public_values.to_vec();
+    verify_public_values(&public_values, &public_inputs[1])?;
+
+    // Prefix the gnark proof as `SP1ProofWithPublicValues::bytes` does, which panics on an invalid
+    // encoded proof. Mock proofs have an empty encoded proof and no prefix.
+    let proof_bytes = if proof.encoded_proof.is_empty() {
+        Vec::new()
+    } else {
+        let encoded_proof = hex::decode(&proof.encoded_proof)
+            .map_err(|_| ConvertError::InvalidProof(ArkGroth16Error::InvalidInput))?;
+        [&proof.groth16_vkey_hash[..4], encoded_proof.as_slice()].concat()
+    };
+    if is_mock_proof(&proof_bytes) {
+        return Err(ConvertError::MockProof);
+    }
+    let (sp1_version, groth16_vk) = match groth16_vk {
+        Some(groth16_vk) => (check_groth16_vk(&proof_bytes, groth16_vk)?, groth16_vk),
+        None => select_groth16_vk(&proof_bytes)?,
+    };
+    let gnark_vk = GnarkGroth16VerifyingKey::from_bytes_with_mode(groth16_vk, mode)
+        .map_err(ConvertError::InvalidVerifyingKey)?;
 
+    convert_parts(
+        &proof_bytes[4..],
+        public_values,
+        public_inputs,
+        sp1_version,
+        &gnark_vk,
+        mode,
+    )
+}
+
+/// Decode the decimal public inputs of an SP1 Groth16 proof into big endian bytes. The 31 byte
+/// vkey hash is left padded with a 0 byte.
+#[cfg(feature = "sp1-sdk")]
+fn decode_sp1_public_inputs(public_inputs: &[String]) -> Result<Vec<[u8; 32]>, ConvertError> {
+    public_inputs
+        .iter()
+        .enumerate()
+        .map(|(index, input)| {
+            decimal_to_be_bytes32(input).ok_or_else(|| match index {
+                0 => ConvertError::InvalidVkeyHash(input.clone()),
+                1 => ConvertError::InvalidCommittedValuesDigest(input.clone()),
+                _ => ConvertError::InvalidPublicInput {
+                    index,
+                    value: input.clone(),
+                },
+            })
+        })
+        .collect()
+}
+
+/// Convert a Groth16 proof of the SP1 mock prover to mock artifacts, tagged with
+/// [`SuiGroth16Artifacts::mock`], which carry the public inputs but no verifying key or proof
+/// points.
+///
+/// Returns [`ConvertError::NotMockProof`] for a real proof. See [`convert_mock`].
+#[cfg(feature = "sp1-sdk")]
+pub fn try_convert_mock(
+    sp1_proof_with_public_values: &SP1ProofWithPublicValues,
+) -> Result<SuiGroth16Artifacts, ConvertError> {
     let proof = sp1_proof_with_public_values
         .proof
-        .try_as_groth_16()
-        .expect("Failed to convert proof to Groth16 proof");
-
-    // Convert vkey hash to bytes.
-    let vkey_hash = BigUint::from_str_radix(&proof.public_inputs[0], 10)
-        .unwrap()
-        .to_bytes_be();
-
-    // To match the standard format, the 31 byte vkey hash is left padded with a 0 byte.
-    let mut padded_vkey_hash = vec![0];
-    padded_vkey_hash.extend_from_slice(&vkey_hash);
-    let vkey_hash = padded_vkey_hash;
-
-    // Ark Proof
-    let ark_proof = load_ark_proof_from_bytes(&proof_bytes[4..]).unwrap();
-    let mut ark_proof_serialized = Vec::new();
-    ark_proof
-        .serialize_compressed(&mut ark_proof_serialized)
-        .unwrap();
-
-    // Ark Public Inputs
-    let mut ark_padded_vkey_hash: [u8; 32] = [0u8; 32];
-    ark_padded_vkey_hash[..vkey_hash.len()].copy_from_slice(&vkey_hash);
-
-    let committed_values_digest = BigUint::from_str_radix(&proof.public_inputs[1], 10)
-        .unwrap()
-        .to_bytes_be();
-    let mut padded_committed_values_digest = [0u8; 32];
-    padded_committed_values_digest[..committed_values_digest.len()]
-        .copy_from_slice(&committed_values_digest);
-
-    let ark_public_inputs =
-        load_ark_public_inputs_from_bytes(&ark_padded_vkey_hash, &padded_committed_values_digest);
-    let mut ark_public_inputs_serialized = Vec::new();
-    ark_public_inputs.iter().for_each(|input| {
-        input
-            .serialize_compressed(&mut ark_public_inputs_serialized)
-            .unwrap();
-    });
-
-    // Ark Groth16
-    let ark_groth16_vk = load_ark_groth16_verifying_key_from_bytes(GROTH16_VK_4_0_0_RC3_BYTES).unwrap();
-    let ark_pvk = prepare_verifying_key(&ark_groth16_vk);
-
-    // Verify Ark proof
-    let ark_verified =
-        Groth16::<Bn254>::verify_with_processed_vk(&ark_pvk, &ark_public_inputs, &ark_proof)
-            .unwrap();
-    assert!(ark_verified);
-
-    // Print Bytes to pass it on the Sui verifier
-    let mut ark_groth16_serialized = Vec::new();
-    ark_groth16_vk
-        .serialize_compressed(&mut ark_groth16_serialized)
-        .unwrap();
-
-    (
-        ark_groth16_serialized,
-        ark_public_inputs_serialized,
-        ark_proof_serialized,
+        .try_as_groth_16_ref()
+        .ok_or(ConvertError::NotGroth16Proof)?;
+    if !proof.encoded_proof.is_empty() {
+        return Err(ConvertError::NotMockProof);
+    }
+    let public_inputs = decode_sp1_public_inputs(&proof.public_inputs)?;
+
+    let public_values = sp1_proof_with_public_values.public_values.to_vec();
+    verify_public_values(&public_values, &public_inputs[1])?;
+    mock_artifacts(public_values, public_inputs)
+}
+
+/// Convert raw SP1 Groth16 proof bytes to the arkworks format expected by the Sui verifier.
+///
+/// `proof_bytes` are the bytes returned by `SP1ProofWithPublicValues::bytes`, `vkey_hash` is the
+/// program vkey hash as returned by `SP1VerifyingKey::bytes32` and `groth16_vk` is the gnark
+/// Groth16 verifying key the proof was made with, such as one of [`GROTH16_VK_REGISTRY`]. The committed
+/// values digest is computed from `public_values`, so this produces the same artifacts as
+/// [`try_convert`] without needing an `SP1ProofWithPublicValues`.
+///
+/// The number of public inputs is taken from `groth16_vk`. Inputs besides the vkey hash and the
+/// committed values digest, such as the exit code, vk root and proof nonce of SP1 v5, are read
+/// from `proof_bytes`, which carry them as 32 byte big endian words between the prefix and the
+/// gnark proof.
+///
+/// Mock proofs, see [`is_mock_proof`], are rejected with [`ConvertError::MockProof`];
+/// convert them with [`convert_mock`].
+pub fn convert_raw(
+    proof_bytes: &[u8],
+    public_values: &[u8],
+    vkey_hash: &str,
+    groth16_vk: &[u8],
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    convert_raw_with_mode(
+        proof_bytes,
+        public_values,
+        vkey_hash,
+        groth16_vk,
+        ValidationMode::default(),
     )
 }
+
+/// Convert raw SP1 Groth16 proof bytes to the arkworks format expected by the Sui verifier,
+/// validating the verifying key and proof points according to `mode`.
+///
+/// See [`convert_raw`].
+pub fn convert_raw_with_mode(
+    proof_bytes: &[u8],
+    public_values: &[u8],
+    vkey_hash: &str,
+    groth16_vk: &[u8],
+    mode: ValidationMode,
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    let vkey_hash = parse_vkey_hash(vkey_hash)?;
+    let committed_values_digest = hash_public_values(public_values);
+    if is_mock_proof(proof_bytes) {
+        return Err(ConvertError::MockProof);
+    }
+    let sp1_version = check_groth16_vk(proof_bytes, groth16_vk)?;
+    let gnark_vk = GnarkGroth16VerifyingKey::from_bytes_with_mode(groth16_vk, mode)
+        .map_err(ConvertError::InvalidVerifyingKey)?;
+
+    // The other public inputs come before the gnark proof, as the SP1 Solidity verifier decodes
+    // them.
+    let num_other_inputs = gnark_vk.num_public_inputs().saturating_sub(2);
+    let (other_inputs, gnark_proof) = proof_bytes[4..]
+        .split_at_checked(32 * num_other_inputs)
+        .ok_or(ConvertError::InvalidProof(ArkGroth16Error::InvalidInput))?;
+    let public_inputs = [vkey_hash, committed_values_digest]
+        .into_iter()
+        .chain(
+            other_inputs
+                .chunks_exact(32)
+                .map(|word| word.try_into().expect("chunks are 32 bytes")),
+        )
+        .collect();
+
+    convert_parts(
+        gnark_proof,
+        public_values.to_vec(),
+        public_inputs,
+        sp1_version,
+        &gnark_vk,
+        mode,
+    )
+}
+
+/// Convert raw SP1 Groth16 proof bytes, selecting the Groth16 verifying key from
+/// [`GROTH16_VK_REGISTRY`] using the 4 byte prefix of the proof.
+///
+/// See [`convert_raw`].
+pub fn convert_raw_with_embedded_vk(
+    proof_bytes: &[u8],
+    public_values: &[u8],
+    vkey_hash: &str,
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    if is_mock_proof(proof_bytes) {
+        return Err(ConvertError::MockProof);
+    }
+    let (_, groth16_vk) = select_groth16_vk(proof_bytes)?;
+    convert_raw(proof_bytes, public_values, vkey_hash, groth16_vk)
+}
+
+/// Convert the proof bytes of the SP1 mock prover to mock artifacts, tagged with
+/// [`SuiGroth16Artifacts::mock`], which carry the public inputs but no verifying key or proof
+/// points. Mock artifacts never verify, but let tests exercise the convert and submit flow
+/// without Groth16 proving.
+///
+/// The other conversions reject mock proofs, so mock artifacts are only produced when asked for.
+/// Returns [`ConvertError::NotMockProof`] if `proof_bytes` are not a mock proof, see
+/// [`is_mock_proof`].
+pub fn convert_mock(
+    proof_bytes: &[u8],
+    public_values: &[u8],
+    vkey_hash: &str,
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    let vkey_hash = parse_vkey_hash(vkey_hash)?;
+    if !is_mock_proof(proof_bytes) {
+        return Err(ConvertError::NotMockProof);
+    }
+    mock_artifacts(
+        public_values.to_vec(),
+        vec![vkey_hash, hash_public_values(public_values)],
+    )
+}
+
+/// Parse a program vkey hash, as returned by `SP1VerifyingKey::bytes32`, with or without a `0x`
+/// prefix.
+fn parse_vkey_hash(vkey_hash: &str) -> Result<[u8; 32], ConvertError> {
+    hex::decode(vkey_hash.strip_prefix("0x").unwrap_or(vkey_hash))
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| ConvertError::InvalidVkeyHash(vkey_hash.to_string()))
+}
+
+/// Whether SP1 proof bytes come from the mock prover, which makes Groth16 proofs without proof
+/// data: `SP1ProofWithPublicValues::bytes` is empty, or only a zero prefix with older SDKs.
+pub fn is_mock_proof(proof_bytes: &[u8]) -> bool {
+    proof_bytes.is_empty() || proof_bytes == [0; 4]
+}
+
+/// Build the artifacts of a mock proof, which carry the public inputs but no verifying key or
+/// proof points.
+fn mock_artifacts(
+    public_values: Vec<u8>,
+    public_inputs: Vec<[u8; 32]>,
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    let mut public_inputs_serialized = Vec::new();
+    for input in load_ark_public_inputs(&public_inputs) {
+        input.serialize_compressed(&mut public_inputs_serialized)?;
+    }
+
+    Ok(SuiGroth16Artifacts {
+        verifying_key: Vec::new(),
+        public_inputs: public_inputs_serialized,
+        proof_points: Vec::new(),
+        sp1_version: "mock".to_string(),
+        vkey_hash: public_inputs[0],
+        committed_values_digest: public_inputs[1],
+        public_values,
+        mock: true,
+    })
+}
+
+/// Convert the proof points, public inputs and verifying key, and check that the proof verifies.
+///
+/// `gnark_proof` is the gnark proof without the SP1 prefix, and `public_inputs` are the big
+/// endian public inputs of the wrapper circuit, starting with the vkey hash and the committed
+/// values digest.
+fn convert_parts(
+    gnark_proof: &[u8],
+    public_values: Vec<u8>,
+    public_inputs: Vec<[u8; 32]>,
+    sp1_version: &str,
+    gnark_vk: &GnarkGroth16VerifyingKey,
+    mode: ValidationMode,
+) -> Result<SuiGroth16Artifacts, ConvertError> {
+    // gnark Proof
+    let gnark_proof: GnarkGroth16Proof = GnarkGroth16Proof::from_bytes_with_mode(gnark_proof, mode)
+        .map_err(ConvertError::InvalidProof)?;
+
+    let SuiCircuitArtifacts {
+        verifying_key,
+        public_inputs: public_inputs_serialized,
+        proof_points,
+        ..
+    } = convert_gnark_parts(
+        gnark_vk,
+        &gnark_proof,
+        &load_ark_public_inputs(&public_inputs),
+    )?;
+
+    Ok(SuiGroth16Artifacts {
+        verifying_key,
+        public_inputs: public_inputs_serialized,
+        proof_points,
+        sp1_version: sp1_version.to_string(),
+        vkey_hash: public_inputs[0],
+        committed_values_digest: public_inputs[1],
+        public_values,
+        mock: false,
+    })
+}
+
+/// Convert an SP1 Groth16 proof to the arkworks format expected by the Sui verifier.
+///
+/// Panics if the conversion fails. See [`try_convert`] for a fallible version.
+#[cfg(feature = "sp1-sdk")]
+pub fn convert_sp1_gnark_to_ark(
+    sp1_proof_with_public_values: SP1ProofWithPublicValues,
+) -> SuiGroth16Artifacts {
+    try_convert(&sp1_proof_with_public_values).expect("Failed to convert SP1 proof to Ark format")
+}
@@ -1,6 +1,11 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use sp1_sdk::SP1ProofWithPublicValues;
-use sp1_sui::convert_sp1_gnark_to_ark;
+use sp1_sui::{
+    Sp1EvmFixture, Sp1Version, SuiCircuitArtifacts, SuiCurve, SuiProofBundle,
+    convert_gnark_groth16_files, convert_snarkjs_groth16_files, convert_sp1_gnark_to_ark,
+    inspect_sui_artifacts, is_mock_proof, load_groth16_vk, try_convert_mock,
+    try_convert_with_groth16_vk,
+};
 
 /// CLI arguments
 #[derive(Parser)]
@@ -9,24 +14,182 @@ struct Args {
     /// Path to the SP1 proof file
     #[arg(short, long, default_value = "../proofs/fibonacci_proof.bin")]
     proof_path: String,
+
+    /// Path to an EVM proof fixture, such as `groth16-fixture.json`, instead of an SP1 proof file
+    #[arg(long)]
+    evm_fixture: Option<String>,
+
+    /// Path to a gnark Groth16 verifying key, instead of the embedded key for the proof version
+    #[arg(long)]
+    vk_path: Option<String>,
+
+    /// Save the converted artifacts to a Sui proof bundle, as JSON if the path ends in `.json`
+    #[arg(long)]
+    bundle_path: Option<String>,
+
+    /// Convert proofs of the SP1 mock prover to mock artifacts instead of rejecting them
+    #[arg(long)]
+    allow_mock: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Decode the hex arguments of a `verify_groth16_bn254_proof` call
+    Inspect {
+        /// Verifying key bytes
+        #[arg(long)]
+        vk: String,
+        /// Public inputs bytes
+        #[arg(long)]
+        inputs: String,
+        /// Proof points bytes
+        #[arg(long)]
+        proof: String,
+        /// SP1 version naming the public inputs, detected from the verifying key by default
+        #[arg(long)]
+        sp1_version: Option<Sp1Version>,
+    },
+    /// Convert a gnark Groth16 proof of any circuit, over BN254 or BLS12-381
+    Gnark {
+        /// Curve of the circuit, `bn254` or `bls12381`
+        #[arg(long, default_value = "bn254")]
+        curve: SuiCurve,
+        /// Path to the verifying key written by `VerifyingKey.WriteTo`
+        #[arg(long)]
+        vk: String,
+        /// Path to the proof written by `Proof.WriteRawTo` or `Proof.WriteTo`
+        #[arg(long)]
+        proof: String,
+        /// Path to the public witness written by `witness.WriteTo`
+        #[arg(long)]
+        witness: String,
+    },
+    /// Convert a snarkjs Groth16 proof of a circom circuit over BN254
+    Snarkjs {
+        /// Path to `verification_key.json`
+        #[arg(long)]
+        vk: String,
+        /// Path to `proof.json`
+        #[arg(long)]
+        proof: String,
+        /// Path to `public.json`
+        #[arg(long)]
+        public: String,
+    },
+}
+
+/// Decode a hex string, with or without a `0x` prefix.
+fn decode_hex(name: &str, value: &str) -> Vec<u8> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value))
+        .unwrap_or_else(|e| panic!("Invalid {name} hex: {e}"))
+}
+
+/// Print the Sui verifier arguments of a gnark or snarkjs circuit.
+fn print_circuit_artifacts(artifacts: &SuiCircuitArtifacts) {
+    println!("\n=== Ark Groth16 Verification Components ===\n");
+    println!("Curve: groth16::{}()\n", artifacts.curve);
+    println!("1. Verifying Key bytes:");
+    println!("---------------------------");
+    println!("{}\n", artifacts.verifying_key_hex());
+    println!("2. Public Inputs bytes:");
+    println!("---------------------------");
+    println!("{}\n", artifacts.public_inputs_hex());
+    println!("3. Proof bytes:");
+    println!("---------------------------");
+    println!("{}\n", artifacts.proof_points_hex());
 }
 
 fn main() {
     // Parse command line arguments
     let args = Args::parse();
 
-    // Read the serialized SP1ProofWithPublicValues from the file.
-    let sp1_proof_with_public_values = SP1ProofWithPublicValues::load(&args.proof_path).unwrap();
+    match args.command {
+        Some(Command::Inspect {
+            vk,
+            inputs,
+            proof,
+            sp1_version,
+        }) => {
+            let mut inspection = inspect_sui_artifacts(
+                &decode_hex("verifying key", &vk),
+                &decode_hex("public inputs", &inputs),
+                &decode_hex("proof points", &proof),
+            );
+            if sp1_version.is_some() {
+                inspection.sp1_version = sp1_version;
+            }
+            print!("{inspection}");
+            return;
+        }
+        Some(Command::Gnark {
+            curve,
+            vk,
+            proof,
+            witness,
+        }) => {
+            let artifacts = convert_gnark_groth16_files(curve, &vk, &proof, &witness)
+                .expect("Failed to convert gnark proof to Ark format");
+            print_circuit_artifacts(&artifacts);
+            return;
+        }
+        Some(Command::Snarkjs { vk, proof, public }) => {
+            let artifacts = convert_snarkjs_groth16_files(&vk, &proof, &public)
+                .expect("Failed to convert snarkjs proof to Ark format");
+            print_circuit_artifacts(&artifacts);
+            return;
+        }
+        None => {}
+    }
+
+    let groth16_vk = args
+        .vk_path
+        .map(|vk_path| load_groth16_vk(&vk_path).unwrap());
+
+    let artifacts = if let Some(fixture_path) = args.evm_fixture {
+        // Read the proof, public values and vkey hash from the EVM fixture.
+        let fixture = Sp1EvmFixture::load(&fixture_path).unwrap();
+        match groth16_vk {
+            _ if args.allow_mock && is_mock_proof(&fixture.proof) => fixture.convert_mock(),
+            Some(groth16_vk) => fixture.convert_with_groth16_vk(&groth16_vk),
+            None => fixture.convert(),
+        }
+        .expect("Failed to convert EVM fixture proof to Ark format")
+    } else {
+        // Read the serialized SP1ProofWithPublicValues from the file.
+        let sp1_proof_with_public_values =
+            SP1ProofWithPublicValues::load(&args.proof_path).unwrap();
+        // The mock prover leaves the encoded proof empty.
+        let mock = sp1_proof_with_public_values
+            .proof
+            .try_as_groth_16_ref()
+            .is_some_and(|proof| proof.encoded_proof.is_empty());
 
-    let (ark_groth16_serialized, ark_public_inputs_serialized, ark_proof_serialized) =
-        convert_sp1_gnark_to_ark(sp1_proof_with_public_values);
+        match groth16_vk {
+            _ if args.allow_mock && mock => try_convert_mock(&sp1_proof_with_public_values)
+                .expect("Failed to convert SP1 mock proof"),
+            Some(groth16_vk) => {
+                try_convert_with_groth16_vk(&sp1_proof_with_public_values, &groth16_vk)
+                    .expect("Failed to convert SP1 proof to Ark format")
+            }
+            None => convert_sp1_gnark_to_ark(sp1_proof_with_public_values),
+        }
+    };
 
-    let ark_groth16_hex: String = hex::encode(ark_groth16_serialized);
-    let ark_public_inputs_hex: String = hex::encode(ark_public_inputs_serialized);
-    let ark_proof_hex: String = hex::encode(ark_proof_serialized);
+    let ark_groth16_hex: String = artifacts.verifying_key_hex();
+    let ark_public_inputs_hex: String = artifacts.public_inputs_hex();
+    let ark_proof_hex: String = artifacts.proof_points_hex();
 
     println!("\n=== Ark Groth16 Verification Components ===\n");
 
+    if artifacts.mock {
+        println!("Mock proof: the artifacts carry no verifying key or proof points\n");
+    }
+    println!("SP1 version: {}", artifacts.sp1_version);
+    println!("Program vkey hash: {}\n", artifacts.vkey_hash_hex());
+
     println!("1. Verifying Key bytes:");
     println!("---------------------------");
     println!("{}\n", ark_groth16_hex);
@@ -38,4 +201,11 @@ fn main() {
     println!("3. Proof bytes:");
     println!("---------------------------");
     println!("{}\n", ark_proof_hex);
+
+    if let Some(bundle_path) = args.bundle_path {
+        SuiProofBundle::new(artifacts)
+            .save(&bundle_path)
+            .expect("Failed to save the Sui proof bundle");
+        println!("Saved Sui proof bundle to {bundle_path}");
+    }
 }
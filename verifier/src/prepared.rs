@@ -0,0 +1,81 @@
+use ark_bn254::Bn254;
+use ark_ec::pairing::Pairing;
+use ark_groth16::VerifyingKey;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{ConvertError, ark_converter::load_ark_groth16_verifying_key_from_bytes};
+
+/// The components of a Sui `groth16::PreparedVerifyingKey`.
+///
+/// Each field is serialized in the byte layout expected by `groth16::pvk_from_bytes`, so that a
+/// contract can store the prepared key once instead of calling `groth16::prepare_verifying_key`
+/// on every verification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SuiPreparedVerifyingKey {
+    /// Concatenated compressed `gamma_abc_g1` points.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub vk_gamma_abc_g1: Vec<u8>,
+    /// Compressed pairing of `alpha_g1` and `beta_g2`.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub alpha_g1_beta_g2: Vec<u8>,
+    /// Compressed negation of `gamma_g2`.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub gamma_g2_neg_pc: Vec<u8>,
+    /// Compressed negation of `delta_g2`.
+    #[cfg_attr(feature = "serde", serde(with = "hex::serde"))]
+    pub delta_g2_neg_pc: Vec<u8>,
+}
+
+impl SuiPreparedVerifyingKey {
+    /// Prepare an arkworks verifying key.
+    pub fn from_ark_vk(vk: &VerifyingKey<Bn254>) -> Result<Self, SerializationError> {
+        let mut vk_gamma_abc_g1 = Vec::new();
+        for point in &vk.gamma_abc_g1 {
+            point.serialize_compressed(&mut vk_gamma_abc_g1)?;
+        }
+        let mut alpha_g1_beta_g2 = Vec::new();
+        Bn254::pairing(vk.alpha_g1, vk.beta_g2)
+            .0
+            .serialize_compressed(&mut alpha_g1_beta_g2)?;
+        let mut gamma_g2_neg_pc = Vec::new();
+        (-vk.gamma_g2).serialize_compressed(&mut gamma_g2_neg_pc)?;
+        let mut delta_g2_neg_pc = Vec::new();
+        (-vk.delta_g2).serialize_compressed(&mut delta_g2_neg_pc)?;
+
+        Ok(Self {
+            vk_gamma_abc_g1,
+            alpha_g1_beta_g2,
+            gamma_g2_neg_pc,
+            delta_g2_neg_pc,
+        })
+    }
+
+    /// Prepare a gnark Groth16 verifying key, such as one of the embedded SP1 keys.
+    pub fn from_groth16_vk(groth16_vk: &[u8]) -> Result<Self, ConvertError> {
+        let vk = load_ark_groth16_verifying_key_from_bytes(groth16_vk)
+            .map_err(ConvertError::InvalidVerifyingKey)?;
+        Ok(Self::from_ark_vk(&vk)?)
+    }
+
+    /// Prepare a compressed arkworks verifying key, as passed to `groth16::prepare_verifying_key`.
+    pub fn from_sui_vk_bytes(vk_bytes: &[u8]) -> Result<Self, SerializationError> {
+        Self::from_ark_vk(&VerifyingKey::deserialize_compressed(vk_bytes)?)
+    }
+
+    /// The BCS bytes of the pure arguments of `groth16::pvk_from_bytes`, in the order of the
+    /// function parameters. Each element is the BCS encoding of a `vector<u8>`, to be wrapped in
+    /// `CallArg::Pure`.
+    #[cfg(feature = "serde")]
+    pub fn pure_args(&self) -> [Vec<u8>; 4] {
+        [
+            &self.vk_gamma_abc_g1,
+            &self.alpha_g1_beta_g2,
+            &self.gamma_g2_neg_pc,
+            &self.delta_g2_neg_pc,
+        ]
+        .map(|bytes| bcs::to_bytes(bytes).expect("BCS encoding of bytes is infallible"))
+    }
+}
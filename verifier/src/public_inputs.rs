@@ -0,0 +1,77 @@
+use std::fmt;
+
+use crate::Sp1Version;
+
+/// A public input of the SP1 Groth16 wrapper circuit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sp1PublicInput {
+    /// Program vkey hash, as returned by `SP1VerifyingKey::bytes32`.
+    VkeyHash,
+    /// Digest of the public values committed by the program.
+    CommittedValuesDigest,
+    /// Exit code of the program.
+    ExitCode,
+    /// Merkle root of the allowed recursion verifying keys.
+    VkRoot,
+    /// Nonce of the proof request.
+    ProofNonce,
+}
+
+/// Public inputs of the wrapper circuit up to SP1 v4.
+const SP1_V2_PUBLIC_INPUTS: &[Sp1PublicInput] = &[
+    Sp1PublicInput::VkeyHash,
+    Sp1PublicInput::CommittedValuesDigest,
+];
+
+/// Public inputs of the wrapper circuit since SP1 v5.
+const SP1_V5_PUBLIC_INPUTS: &[Sp1PublicInput] = &[
+    Sp1PublicInput::VkeyHash,
+    Sp1PublicInput::CommittedValuesDigest,
+    Sp1PublicInput::ExitCode,
+    Sp1PublicInput::VkRoot,
+    Sp1PublicInput::ProofNonce,
+];
+
+impl Sp1PublicInput {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::VkeyHash => "vkey_hash",
+            Self::CommittedValuesDigest => "committed_values_digest",
+            Self::ExitCode => "exit_code",
+            Self::VkRoot => "vk_root",
+            Self::ProofNonce => "proof_nonce",
+        }
+    }
+}
+
+impl fmt::Display for Sp1PublicInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The public inputs of a wrapper circuit with `num_public_inputs` inputs, in circuit order.
+///
+/// `num_public_inputs` is `gamma_abc_g1.len() - 1` of the verifying key. Returns `None` if no
+/// known SP1 wrapper circuit has this number of public inputs.
+pub fn sp1_public_input_schema(num_public_inputs: usize) -> Option<&'static [Sp1PublicInput]> {
+    [SP1_V2_PUBLIC_INPUTS, SP1_V5_PUBLIC_INPUTS]
+        .into_iter()
+        .find(|schema| schema.len() == num_public_inputs)
+}
+
+impl Sp1Version {
+    /// The public inputs of the wrapper circuit of this version, in circuit order.
+    pub fn public_input_schema(self) -> &'static [Sp1PublicInput] {
+        let major = self
+            .as_str()
+            .trim_start_matches('v')
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u32>().ok());
+        match major {
+            Some(major) if major >= 5 => SP1_V5_PUBLIC_INPUTS,
+            _ => SP1_V2_PUBLIC_INPUTS,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use sha2::{Digest, Sha256};
+
+use crate::ConvertError;
+
+/// Hash the SP1 public values into the committed values digest.
+///
+/// This is the SHA-256 hash of the public values with the top 3 bits zeroed out, so that it fits
+/// in the BN254 scalar field. The same logic happens in the SP1 Groth16 verifier.
+pub fn hash_public_values(public_values: &[u8]) -> [u8; 32] {
+    let mut digest: [u8; 32] = Sha256::digest(public_values).into();
+    digest[0] &= 0x1F;
+    digest
+}
+
+/// Check that the public values hash to the committed values digest of a proof.
+pub fn verify_public_values(
+    public_values: &[u8],
+    committed_values_digest: &[u8; 32],
+) -> Result<(), ConvertError> {
+    if hash_public_values(public_values) != *committed_values_digest {
+        return Err(ConvertError::PublicValuesMismatch);
+    }
+    Ok(())
+}
@@ -0,0 +1,362 @@
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_groth16::{Proof, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::ConvertError;
+use crate::circuit::{SuiCircuitArtifacts, verified_artifacts};
+
+/// Errors returned when parsing the JSON files written by snarkjs.
+#[derive(Error, Debug)]
+pub enum SnarkjsError {
+    #[error("Invalid snarkjs JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Unsupported snarkjs protocol {0}, only groth16 is supported")]
+    UnsupportedProtocol(String),
+    #[error("Unsupported snarkjs curve {0}, only bn128 is supported")]
+    UnsupportedCurve(String),
+    #[error("Invalid field element {field}: {value}")]
+    InvalidFieldElement { field: String, value: String },
+    #[error("Invalid point {0}: not on curve or not in subgroup")]
+    InvalidPoint(String),
+    #[error("Verifying key declares {n_public} public inputs but has {ic} IC points")]
+    PublicInputCountMismatch { n_public: usize, ic: usize },
+}
+
+/// A G1 point in snarkjs JSON: the decimal `[x, y, z]` Jacobian coordinates.
+pub type SnarkjsG1 = [String; 3];
+
+/// A G2 point in snarkjs JSON: the decimal `[[x.c0, x.c1], [y.c0, y.c1], [z.c0, z.c1]]` Jacobian
+/// coordinates.
+pub type SnarkjsG2 = [[String; 2]; 3];
+
+/// A Groth16 verifying key as written by `snarkjs zkey export verificationkey`.
+///
+/// `vk_alphabeta_12`, the precomputed pairing of alpha and beta, is not needed and is ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnarkjsVerifyingKey {
+    pub protocol: String,
+    pub curve: String,
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    pub vk_alpha_1: SnarkjsG1,
+    pub vk_beta_2: SnarkjsG2,
+    pub vk_gamma_2: SnarkjsG2,
+    pub vk_delta_2: SnarkjsG2,
+    #[serde(rename = "IC")]
+    pub ic: Vec<SnarkjsG1>,
+}
+
+/// A Groth16 proof as written by `snarkjs groth16 prove`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnarkjsProof {
+    pub pi_a: SnarkjsG1,
+    pub pi_b: SnarkjsG2,
+    pub pi_c: SnarkjsG1,
+    pub protocol: String,
+    pub curve: String,
+}
+
+fn check_protocol(protocol: &str, curve: &str) -> Result<(), SnarkjsError> {
+    if protocol != "groth16" {
+        return Err(SnarkjsError::UnsupportedProtocol(protocol.to_string()));
+    }
+    if curve != "bn128" {
+        return Err(SnarkjsError::UnsupportedCurve(curve.to_string()));
+    }
+    Ok(())
+}
+
+/// Parse a canonical decimal field element. Signs, leading `+` and values at or above the
+/// modulus are rejected rather than reduced.
+fn parse_field<F: PrimeField>(field: &str, value: &str) -> Result<F, SnarkjsError> {
+    let invalid = || SnarkjsError::InvalidFieldElement {
+        field: field.to_string(),
+        value: value.to_string(),
+    };
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let bigint = F::BigInt::from_str(value).map_err(|_| invalid())?;
+    F::from_bigint(bigint).ok_or_else(invalid)
+}
+
+fn parse_fq2(field: &str, [c0, c1]: &[String; 2]) -> Result<Fq2, SnarkjsError> {
+    Ok(Fq2::new(
+        parse_field(&format!("{field}.c0"), c0)?,
+        parse_field(&format!("{field}.c1"), c1)?,
+    ))
+}
+
+fn parse_g1(field: &str, [x, y, z]: &SnarkjsG1) -> Result<G1Affine, SnarkjsError> {
+    let point = G1Projective::new_unchecked(
+        parse_field::<Fq>(&format!("{field}.x"), x)?,
+        parse_field(&format!("{field}.y"), y)?,
+        parse_field(&format!("{field}.z"), z)?,
+    )
+    .into_affine();
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(SnarkjsError::InvalidPoint(field.to_string()));
+    }
+    Ok(point)
+}
+
+fn parse_g2(field: &str, [x, y, z]: &SnarkjsG2) -> Result<G2Affine, SnarkjsError> {
+    let point = G2Projective::new_unchecked(
+        parse_fq2(&format!("{field}.x"), x)?,
+        parse_fq2(&format!("{field}.y"), y)?,
+        parse_fq2(&format!("{field}.z"), z)?,
+    )
+    .into_affine();
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(SnarkjsError::InvalidPoint(field.to_string()));
+    }
+    Ok(point)
+}
+
+impl SnarkjsVerifyingKey {
+    pub fn from_json(json: &str) -> Result<Self, SnarkjsError> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+impl SnarkjsProof {
+    pub fn from_json(json: &str) -> Result<Self, SnarkjsError> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+impl TryFrom<&SnarkjsVerifyingKey> for VerifyingKey<Bn254> {
+    type Error = SnarkjsError;
+
+    fn try_from(vk: &SnarkjsVerifyingKey) -> Result<Self, Self::Error> {
+        check_protocol(&vk.protocol, &vk.curve)?;
+        if vk.ic.len() != vk.n_public + 1 {
+            return Err(SnarkjsError::PublicInputCountMismatch {
+                n_public: vk.n_public,
+                ic: vk.ic.len(),
+            });
+        }
+        Ok(VerifyingKey {
+            alpha_g1: parse_g1("vk_alpha_1", &vk.vk_alpha_1)?,
+            beta_g2: parse_g2("vk_beta_2", &vk.vk_beta_2)?,
+            gamma_g2: parse_g2("vk_gamma_2", &vk.vk_gamma_2)?,
+            delta_g2: parse_g2("vk_delta_2", &vk.vk_delta_2)?,
+            gamma_abc_g1: vk
+                .ic
+                .iter()
+                .enumerate()
+                .map(|(i, point)| parse_g1(&format!("IC[{i}]"), point))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&SnarkjsProof> for Proof<Bn254> {
+    type Error = SnarkjsError;
+
+    fn try_from(proof: &SnarkjsProof) -> Result<Self, Self::Error> {
+        check_protocol(&proof.protocol, &proof.curve)?;
+        Ok(Proof {
+            a: parse_g1("pi_a", &proof.pi_a)?,
+            b: parse_g2("pi_b", &proof.pi_b)?,
+            c: parse_g1("pi_c", &proof.pi_c)?,
+        })
+    }
+}
+
+/// Parse a snarkjs `verification_key.json` into an arkworks verifying key.
+pub fn parse_snarkjs_verifying_key(json: &str) -> Result<VerifyingKey<Bn254>, SnarkjsError> {
+    VerifyingKey::try_from(&SnarkjsVerifyingKey::from_json(json)?)
+}
+
+/// Parse a snarkjs `proof.json` into an arkworks proof.
+pub fn parse_snarkjs_proof(json: &str) -> Result<Proof<Bn254>, SnarkjsError> {
+    Proof::try_from(&SnarkjsProof::from_json(json)?)
+}
+
+/// Parse a snarkjs `public.json`, the decimal public signals of the circuit in order.
+pub fn parse_snarkjs_public_signals(json: &str) -> Result<Vec<Fr>, SnarkjsError> {
+    let signals: Vec<String> = serde_json::from_str(json)?;
+    signals
+        .iter()
+        .enumerate()
+        .map(|(i, signal)| parse_field(&format!("public[{i}]"), signal))
+        .collect()
+}
+
+/// Convert a snarkjs Groth16 proof of a circom circuit over BN254 to the arkworks format
+/// expected by the Sui verifier.
+///
+/// `vk_json`, `proof_json` and `public_json` are the contents of the `verification_key.json`,
+/// `proof.json` and `public.json` files written by snarkjs. The proof is checked against the
+/// verifying key before it is returned.
+pub fn convert_snarkjs_groth16(
+    vk_json: &str,
+    proof_json: &str,
+    public_json: &str,
+) -> Result<SuiCircuitArtifacts, ConvertError> {
+    let vk = parse_snarkjs_verifying_key(vk_json)?;
+    let proof = parse_snarkjs_proof(proof_json)?;
+    let public_inputs = parse_snarkjs_public_signals(public_json)?;
+    verified_artifacts(&vk, &public_inputs, &proof)
+}
+
+/// Convert the snarkjs verifying key, proof and public signals files of a circuit.
+///
+/// See [`convert_snarkjs_groth16`].
+pub fn convert_snarkjs_groth16_files(
+    vk_path: impl AsRef<Path>,
+    proof_path: impl AsRef<Path>,
+    public_path: impl AsRef<Path>,
+) -> Result<SuiCircuitArtifacts, ConvertError> {
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|source| ConvertError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    };
+    convert_snarkjs_groth16(
+        &read(vk_path.as_ref())?,
+        &read(proof_path.as_ref())?,
+        &read(public_path.as_ref())?,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ec::AffineRepr;
+    use ark_ff::{One, Zero};
+
+    use super::*;
+
+    fn g1_json(point: G1Affine) -> SnarkjsG1 {
+        [point.x.to_string(), point.y.to_string(), "1".to_string()]
+    }
+
+    #[test]
+    fn test_parse_field() {
+        assert_eq!(parse_field::<Fr>("f", "0").unwrap(), Fr::zero());
+        assert_eq!(parse_field::<Fr>("f", "33").unwrap(), Fr::from(33u64));
+        let max = (-Fr::one()).to_string();
+        assert_eq!(parse_field::<Fr>("f", &max).unwrap(), -Fr::one());
+
+        let modulus = Fr::MODULUS.to_string();
+        for value in ["", "-1", "+1", "0x21", " 1", "1.0", modulus.as_str()] {
+            assert!(matches!(
+                parse_field::<Fr>("f", value),
+                Err(SnarkjsError::InvalidFieldElement { field, value: v })
+                    if field == "f" && v == value
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_g1() {
+        let generator = G1Affine::generator();
+        assert_eq!(parse_g1("p", &g1_json(generator)).unwrap(), generator);
+
+        // Jacobian coordinates are normalized, and `z = 0` is the point at infinity.
+        let (x, y) = (generator.x, generator.y);
+        let jacobian = [
+            (x * Fq::from(4u64)).to_string(),
+            (y * Fq::from(8u64)).to_string(),
+            "2".to_string(),
+        ];
+        assert_eq!(parse_g1("p", &jacobian).unwrap(), generator);
+        let infinity = ["0".to_string(), "1".to_string(), "0".to_string()];
+        assert!(parse_g1("p", &infinity).unwrap().is_zero());
+
+        let mut off_curve = g1_json(generator);
+        off_curve[1] = "3".to_string();
+        assert!(matches!(
+            parse_g1("p", &off_curve),
+            Err(SnarkjsError::InvalidPoint(field)) if field == "p"
+        ));
+        off_curve[1] = "y".to_string();
+        assert!(matches!(
+            parse_g1("p", &off_curve),
+            Err(SnarkjsError::InvalidFieldElement { field, .. }) if field == "p.y"
+        ));
+    }
+
+    #[test]
+    fn test_parse_g2() {
+        let generator = G2Affine::generator();
+        let fq2 = |value: Fq2| [value.c0.to_string(), value.c1.to_string()];
+        let mut point = [fq2(generator.x), fq2(generator.y), fq2(Fq2::one())];
+        assert_eq!(parse_g2("p", &point).unwrap(), generator);
+
+        point[1][0] = "3".to_string();
+        assert!(matches!(
+            parse_g2("p", &point),
+            Err(SnarkjsError::InvalidPoint(field)) if field == "p"
+        ));
+        point[1][1] = String::new();
+        assert!(matches!(
+            parse_g2("p", &point),
+            Err(SnarkjsError::InvalidFieldElement { field, .. }) if field == "p.y.c1"
+        ));
+    }
+
+    #[test]
+    fn test_parse_public_signals() {
+        assert_eq!(
+            parse_snarkjs_public_signals(r#"["1", "33"]"#).unwrap(),
+            [Fr::one(), Fr::from(33u64)]
+        );
+        assert!(parse_snarkjs_public_signals("[]").unwrap().is_empty());
+        assert!(matches!(
+            parse_snarkjs_public_signals(r#"["1", "x"]"#),
+            Err(SnarkjsError::InvalidFieldElement { field, .. }) if field == "public[1]"
+        ));
+        assert!(matches!(
+            parse_snarkjs_public_signals("[1]"),
+            Err(SnarkjsError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_protocol() {
+        assert!(check_protocol("groth16", "bn128").is_ok());
+        assert!(matches!(
+            check_protocol("plonk", "bn128"),
+            Err(SnarkjsError::UnsupportedProtocol(protocol)) if protocol == "plonk"
+        ));
+        assert!(matches!(
+            check_protocol("groth16", "bls12381"),
+            Err(SnarkjsError::UnsupportedCurve(curve)) if curve == "bls12381"
+        ));
+    }
+
+    #[test]
+    fn test_proof_conversion() {
+        let a = G1Affine::generator();
+        let b = G2Affine::generator();
+        let c = (a * Fr::from(2u64)).into_affine();
+        let fq2 = |value: Fq2| [value.c0.to_string(), value.c1.to_string()];
+        let proof = SnarkjsProof {
+            pi_a: g1_json(a),
+            pi_b: [fq2(b.x), fq2(b.y), fq2(Fq2::one())],
+            pi_c: g1_json(c),
+            protocol: "groth16".to_string(),
+            curve: "bn128".to_string(),
+        };
+        let json = serde_json::to_string(&proof).unwrap();
+        assert_eq!(SnarkjsProof::from_json(&json).unwrap(), proof);
+        assert_eq!(parse_snarkjs_proof(&json).unwrap(), Proof { a, b, c });
+
+        let mut proof = proof;
+        proof.protocol = "fflonk".to_string();
+        assert!(matches!(
+            Proof::try_from(&proof),
+            Err(SnarkjsError::UnsupportedProtocol(_))
+        ));
+    }
+}
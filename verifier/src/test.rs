@@ -1,26 +1,65 @@
 use ark_bn254::Bn254;
-use ark_groth16::{prepare_verifying_key, Groth16};
+use ark_groth16::Groth16;
+#[cfg(feature = "sp1-sdk")]
+use ark_groth16::prepare_verifying_key;
 use ark_serialize::CanonicalSerialize;
 use ark_snark::SNARK;
+use hex_literal::hex;
 use num_bigint::BigUint;
+#[cfg(feature = "sp1-sdk")]
 use num_traits::Num;
-use sp1_sdk::SP1ProofWithPublicValues;
+#[cfg(feature = "sp1-sdk")]
+use sp1_sdk::{SP1Proof, SP1ProofWithPublicValues, SP1PublicValues};
 use sp1_verifier::Groth16Verifier;
 
+use crate::{
+    ConvertError, GROTH16_VK_3_0_0_BYTES, GROTH16_VK_4_0_0_RC3_BYTES, SuiGroth16Artifacts,
+    convert_raw, convert_raw_with_embedded_vk, select_groth16_vk,
+};
+#[cfg(feature = "sp1-sdk")]
+use crate::{hash_public_values, try_convert};
+
+/// `SP1ProofWithPublicValues::bytes` of the Groth16 proof of the Fibonacci program in `proofs`,
+/// as in `proofs/fibonacci_fixture.json`, available without the SP1 SDK.
+const FIBONACCI_PROOF: [u8; 260] = hex!(
+    "11b6a09d027885899a8a8060cb07f0b65a2acac245bacfa9f11cf06e540d5b672d9a0151164c66c0ee0ca9541bd2b06e6edc4dcf9e83cc16223511c9727b08b1e5ed7d8c0afc08213c56f7fbb816de6da5bed36ca2abd71db76e253264cebefbe1e88b2013e9893a7f993b07310da1af3bfb6b38fbac6322abc1b997d9d64ba41aab47cd15c3e767ece8954ededb0cd9684dea06f111d3803c062ea20d7c728d8829f67e0561205ce9f1f16ba809e94e39c5f3b04c76340cf7f73cdd2bed97efd4bb744f1d01f03f7fa0595da5250985b0ebfaf868745c0f463c990be40f3ff8c77edd9007bf70cd5c37b7568a6a1f3a554bb9d9b86912b55ad18198ab274bddde47e1e8"
+);
+/// Public values of [`FIBONACCI_PROOF`].
+const FIBONACCI_PUBLIC_VALUES: [u8; 96] = hex!(
+    "00000000000000000000000000000000000000000000000000000000000000140000000000000000000000000000000000000000000000000000000000001a6d0000000000000000000000000000000000000000000000000000000000002ac2"
+);
+/// Program vkey hash of [`FIBONACCI_PROOF`].
+const FIBONACCI_VKEY_HASH: &str =
+    "0x00643545b9a0436e71ec17b98049f8f75ca54f277b8e8b89e4c641725c90ff81";
+
+/// The Sui artifacts of [`FIBONACCI_PROOF`].
+fn fibonacci_artifacts() -> SuiGroth16Artifacts {
+    convert_raw_with_embedded_vk(
+        &FIBONACCI_PROOF,
+        &FIBONACCI_PUBLIC_VALUES,
+        FIBONACCI_VKEY_HASH,
+    )
+    .unwrap()
+}
+
+/// The Groth16 proof of the Fibonacci program in `proofs`, as saved by the SP1 SDK.
+#[cfg(feature = "sp1-sdk")]
+fn fibonacci_proof() -> SP1ProofWithPublicValues {
+    SP1ProofWithPublicValues::load("../proofs/fibonacci_proof.bin").unwrap()
+}
+
+#[cfg(feature = "sp1-sdk")]
 #[test]
 fn test_convert_gnark_to_ark() {
     use crate::ark_converter::{
         load_ark_groth16_verifying_key_from_bytes, load_ark_proof_from_bytes,
         load_ark_public_inputs_from_bytes,
     };
-    use crate::GROTH16_VK_3_0_0_BYTES;
 
-    // Read the serialized SP1ProofWithPublicValues from the file.
-    let sp1_proof_with_public_values_file = "../proofs/fibonacci_proof.bin";
-    let sp1_proof_with_public_values =
-        SP1ProofWithPublicValues::load(&sp1_proof_with_public_values_file).unwrap();
+    let sp1_proof_with_public_values = fibonacci_proof();
 
     let proof_bytes = sp1_proof_with_public_values.bytes();
+    let (_, groth16_vk) = select_groth16_vk(&proof_bytes).unwrap();
     let sp1_public_inputs = sp1_proof_with_public_values.public_values.to_vec();
 
     let proof = sp1_proof_with_public_values
@@ -44,7 +83,7 @@ fn test_convert_gnark_to_ark() {
         &proof_bytes,
         &sp1_public_inputs,
         &sp1_vkey_hash,
-        &GROTH16_VK_3_0_0_BYTES,
+        groth16_vk,
     );
     // Check that SP1 test proof is correct before converting
     assert!(sp1_verified.is_ok());
@@ -78,7 +117,7 @@ fn test_convert_gnark_to_ark() {
     });
 
     // Construct Ark Groth16 Verifying Key
-    let ark_groth16_vk = load_ark_groth16_verifying_key_from_bytes(GROTH16_VK_3_0_0_BYTES).unwrap();
+    let ark_groth16_vk = load_ark_groth16_verifying_key_from_bytes(groth16_vk).unwrap();
     let ark_pvk = prepare_verifying_key(&ark_groth16_vk);
 
     // Verify Ark Groth16 proof
@@ -100,3 +139,1699 @@ fn test_convert_gnark_to_ark() {
         hex::encode(ark_public_inputs_serialized)
     );
 }
+
+#[cfg(feature = "sp1-sdk")]
+#[test]
+fn test_try_convert() {
+    let sp1_proof_with_public_values = fibonacci_proof();
+
+    let artifacts = try_convert(&sp1_proof_with_public_values).unwrap();
+    assert_eq!(artifacts.public_inputs.len(), 64);
+    assert_eq!(artifacts.proof_points.len(), 128);
+    assert!(!artifacts.verifying_key.is_empty());
+    assert_eq!(artifacts.sp1_version, "v4.0.0-rc.3");
+    assert_eq!(
+        artifacts.verified_public_values().unwrap(),
+        sp1_proof_with_public_values.public_values.as_slice()
+    );
+
+    #[cfg(feature = "serde")]
+    {
+        // The call arguments are BCS encoded byte vectors, in the order expected on-chain.
+        let [vk_arg, public_inputs_arg, proof_points_arg] = artifacts.pure_args();
+        assert_eq!(
+            bcs::from_bytes::<Vec<u8>>(&vk_arg).unwrap(),
+            artifacts.verifying_key
+        );
+        assert_eq!(
+            bcs::from_bytes::<Vec<u8>>(&public_inputs_arg).unwrap(),
+            artifacts.public_inputs
+        );
+        assert_eq!(
+            bcs::from_bytes::<Vec<u8>>(&proof_points_arg).unwrap(),
+            artifacts.proof_points
+        );
+
+        let json = serde_json::to_string(&artifacts).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SuiGroth16Artifacts>(&json).unwrap(),
+            artifacts
+        );
+    }
+}
+
+#[cfg(feature = "sp1-sdk")]
+#[test]
+fn test_try_convert_errors() {
+    let mut sp1_proof_with_public_values = fibonacci_proof();
+
+    // Corrupt the vkey hash.
+    let mut bad_vkey_hash = sp1_proof_with_public_values.clone();
+    if let SP1Proof::Groth16(proof) = &mut bad_vkey_hash.proof {
+        proof.public_inputs[0] = "not a number".to_string();
+    }
+    assert!(matches!(
+        try_convert(&bad_vkey_hash),
+        Err(ConvertError::InvalidVkeyHash(_))
+    ));
+
+    // Public values that do not hash to the committed values digest are rejected.
+    let mut bad_public_values = sp1_proof_with_public_values.clone();
+    bad_public_values.public_values = SP1PublicValues::from(&[1, 2, 3]);
+    assert!(matches!(
+        try_convert(&bad_public_values),
+        Err(ConvertError::PublicValuesMismatch)
+    ));
+
+    // A proof for another committed values digest must fail self-verification.
+    let mut bad_digest = bad_public_values;
+    if let SP1Proof::Groth16(proof) = &mut bad_digest.proof {
+        proof.public_inputs[1] =
+            BigUint::from_bytes_be(&hash_public_values(&[1, 2, 3])).to_string();
+    }
+    assert!(matches!(
+        try_convert(&bad_digest),
+        Err(ConvertError::VerificationFailed)
+    ));
+
+    // An encoded proof that is not hex is rejected without panicking.
+    let mut bad_encoded_proof = sp1_proof_with_public_values.clone();
+    if let SP1Proof::Groth16(proof) = &mut bad_encoded_proof.proof {
+        proof.encoded_proof = "not hex".to_string();
+    }
+    assert!(matches!(
+        try_convert(&bad_encoded_proof),
+        Err(ConvertError::InvalidProof(_))
+    ));
+
+    // Non-Groth16 proofs are rejected without panicking.
+    sp1_proof_with_public_values.proof = SP1Proof::Core(vec![]);
+    assert!(matches!(
+        try_convert(&sp1_proof_with_public_values),
+        Err(ConvertError::NotGroth16Proof)
+    ));
+}
+
+#[test]
+fn test_convert_raw() {
+    let vkey_hash = FIBONACCI_VKEY_HASH.to_string();
+    let public_values = FIBONACCI_PUBLIC_VALUES.to_vec();
+    let proof_bytes = FIBONACCI_PROOF.to_vec();
+    let artifacts = convert_raw(
+        &proof_bytes,
+        &public_values,
+        &vkey_hash,
+        GROTH16_VK_4_0_0_RC3_BYTES,
+    )
+    .unwrap();
+    assert_eq!(artifacts.sp1_version, "v4.0.0-rc.3");
+    assert_eq!(artifacts.vkey_hash_hex(), vkey_hash);
+    #[cfg(feature = "sp1-sdk")]
+    assert_eq!(artifacts, try_convert(&fibonacci_proof()).unwrap());
+
+    // The verifying key must match the prefix of the proof.
+    assert!(matches!(
+        convert_raw(
+            &proof_bytes,
+            &public_values,
+            &vkey_hash,
+            GROTH16_VK_3_0_0_BYTES
+        ),
+        Err(ConvertError::VerifyingKeyMismatch { .. })
+    ));
+    assert!(matches!(
+        convert_raw(
+            &proof_bytes,
+            &public_values,
+            "0x1234",
+            GROTH16_VK_4_0_0_RC3_BYTES
+        ),
+        Err(ConvertError::InvalidVkeyHash(_))
+    ));
+    assert!(matches!(
+        convert_raw(
+            &proof_bytes,
+            &[1, 2, 3],
+            &vkey_hash,
+            GROTH16_VK_4_0_0_RC3_BYTES
+        ),
+        Err(ConvertError::VerificationFailed)
+    ));
+}
+
+#[cfg(feature = "sp1-sdk")]
+#[test]
+fn test_mock_proof() {
+    use crate::{SuiVerifyError, convert_mock, is_mock_proof, try_convert_mock};
+
+    let sp1_proof_with_public_values = fibonacci_proof();
+    let artifacts = try_convert(&sp1_proof_with_public_values).unwrap();
+    assert!(!artifacts.mock);
+    assert!(!is_mock_proof(&sp1_proof_with_public_values.bytes()));
+
+    // The mock prover leaves the encoded proof empty.
+    let mut mock_proof = sp1_proof_with_public_values.clone();
+    let SP1Proof::Groth16(groth16_proof) = &mut mock_proof.proof else {
+        unreachable!()
+    };
+    groth16_proof.encoded_proof.clear();
+    groth16_proof.raw_proof.clear();
+    assert!(is_mock_proof(&mock_proof.bytes()));
+    assert!(is_mock_proof(&[0; 4]));
+
+    // Mock proofs are rejected unless converted as such.
+    assert!(matches!(
+        try_convert(&mock_proof),
+        Err(ConvertError::MockProof)
+    ));
+    assert!(matches!(
+        try_convert_mock(&sp1_proof_with_public_values),
+        Err(ConvertError::NotMockProof)
+    ));
+    let mock = try_convert_mock(&mock_proof).unwrap();
+    assert!(mock.mock);
+    assert_eq!(mock.sp1_version, "mock");
+    assert!(mock.verifying_key.is_empty());
+    assert!(mock.proof_points.is_empty());
+    assert_eq!(mock.public_inputs, artifacts.public_inputs);
+    assert_eq!(mock.vkey_hash, artifacts.vkey_hash);
+    assert_eq!(
+        mock.committed_values_digest,
+        artifacts.committed_values_digest
+    );
+    assert_eq!(
+        mock.verified_public_values().unwrap(),
+        artifacts.public_values
+    );
+    assert!(matches!(mock.verify(), Err(SuiVerifyError::MockProof)));
+
+    // Raw mock proof bytes convert to the same artifacts.
+    assert_eq!(
+        convert_mock(&[], &artifacts.public_values, &artifacts.vkey_hash_hex()).unwrap(),
+        mock
+    );
+}
+
+#[test]
+fn test_convert_mock() {
+    use crate::{SuiVerifyError, convert_mock, convert_raw_with_embedded_vk, is_mock_proof};
+
+    let vkey_hash = FIBONACCI_VKEY_HASH.to_string();
+    let public_values = FIBONACCI_PUBLIC_VALUES.to_vec();
+    let proof_bytes = FIBONACCI_PROOF.to_vec();
+    let artifacts = fibonacci_artifacts();
+    assert!(!artifacts.mock);
+    assert!(!is_mock_proof(&proof_bytes));
+
+    // The mock prover makes empty proof bytes, or a zero prefix with older SDKs, which are only
+    // converted as mock proofs.
+    for mock_bytes in [&[][..], &[0; 4]] {
+        assert!(is_mock_proof(mock_bytes));
+        assert!(matches!(
+            convert_raw_with_embedded_vk(mock_bytes, &public_values, &vkey_hash),
+            Err(ConvertError::MockProof)
+        ));
+        assert!(matches!(
+            convert_raw(
+                mock_bytes,
+                &public_values,
+                &vkey_hash,
+                GROTH16_VK_4_0_0_RC3_BYTES
+            ),
+            Err(ConvertError::MockProof)
+        ));
+        let mock = convert_mock(mock_bytes, &public_values, &vkey_hash).unwrap();
+        assert!(mock.mock);
+        assert_eq!(mock.sp1_version, "mock");
+        assert!(mock.verifying_key.is_empty());
+        assert!(mock.proof_points.is_empty());
+        assert_eq!(mock.public_inputs, artifacts.public_inputs);
+        assert_eq!(mock.verified_public_values().unwrap(), public_values);
+        assert!(matches!(mock.verify(), Err(SuiVerifyError::MockProof)));
+    }
+    assert_eq!(
+        convert_raw_with_embedded_vk(&proof_bytes, &public_values, &vkey_hash).unwrap(),
+        artifacts
+    );
+    assert!(matches!(
+        convert_mock(&proof_bytes, &public_values, &vkey_hash),
+        Err(ConvertError::NotMockProof)
+    ));
+
+    #[cfg(feature = "serde")]
+    {
+        // Mock artifacts keep their tag through JSON, and artifacts without the field are real.
+        let mock = convert_mock(&[], &public_values, &vkey_hash).unwrap();
+        let json = serde_json::to_value(&mock).unwrap();
+        assert_eq!(json["mock"], true);
+        assert_eq!(
+            serde_json::from_value::<SuiGroth16Artifacts>(json).unwrap(),
+            mock
+        );
+        let mut json = serde_json::to_value(&artifacts).unwrap();
+        json.as_object_mut().unwrap().remove("mock");
+        assert_eq!(
+            serde_json::from_value::<SuiGroth16Artifacts>(json).unwrap(),
+            artifacts
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_sui_proof_bundle() {
+    use crate::{BundleError, SUI_PROOF_BUNDLE_VERSION, SuiProofBundle};
+
+    let artifacts = fibonacci_artifacts();
+    let bundle = SuiProofBundle::new(artifacts.clone());
+    assert_eq!(bundle.version, SUI_PROOF_BUNDLE_VERSION);
+    assert_eq!(bundle.converter_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(bundle.artifacts.sp1_version, "v4.0.0-rc.3");
+
+    // Both encodings round trip, and the binary encoding does not hex encode the bytes.
+    let json = bundle.to_json();
+    assert_eq!(SuiProofBundle::from_json(&json).unwrap(), bundle);
+    let bytes = bundle.to_bytes();
+    assert_eq!(SuiProofBundle::from_bytes(&bytes).unwrap(), bundle);
+    assert!(bytes.len() < json.len() / 2);
+
+    let dir = std::env::temp_dir().join(format!("sp1-sui-bundle-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for name in ["bundle.json", "bundle.bin"] {
+        let path = dir.join(name);
+        bundle.save(&path).unwrap();
+        assert_eq!(SuiProofBundle::load(&path).unwrap(), bundle);
+    }
+    assert!(
+        std::fs::read(dir.join("bundle.json"))
+            .unwrap()
+            .starts_with(b"{")
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(matches!(
+        SuiProofBundle::load(dir.join("bundle.bin")),
+        Err(BundleError::Io { .. })
+    ));
+
+    // Unknown versions are rejected, unknown JSON fields are ignored.
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["future_field"] = true.into();
+    assert_eq!(
+        SuiProofBundle::from_json(&value.to_string()).unwrap(),
+        bundle
+    );
+    value["version"] = 2.into();
+    assert!(matches!(
+        SuiProofBundle::from_json(&value.to_string()),
+        Err(BundleError::UnsupportedVersion {
+            version: 2,
+            supported: 1
+        })
+    ));
+    let mut newer = bytes.clone();
+    newer[8] = 2;
+    assert!(matches!(
+        SuiProofBundle::from_bytes(&newer),
+        Err(BundleError::UnsupportedVersion { version: 2, .. })
+    ));
+    value["version"] = 0.into();
+    assert!(matches!(
+        SuiProofBundle::from_json(&value.to_string()),
+        Err(BundleError::UnsupportedVersion { version: 0, .. })
+    ));
+    newer[8] = 0;
+    assert!(matches!(
+        SuiProofBundle::from_bytes(&newer),
+        Err(BundleError::UnsupportedVersion { version: 0, .. })
+    ));
+    assert!(matches!(
+        SuiProofBundle::from_bytes(&bytes[1..]),
+        Err(BundleError::InvalidMagic)
+    ));
+    assert!(matches!(
+        SuiProofBundle::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(BundleError::Binary(_))
+    ));
+}
+
+#[cfg(all(feature = "sp1-sdk", feature = "serde"))]
+#[test]
+fn test_convert_evm_fixture() {
+    use crate::{EvmFixtureError, Sp1EvmFixture};
+
+    let sp1_proof_with_public_values = fibonacci_proof();
+    let artifacts = try_convert(&sp1_proof_with_public_values).unwrap();
+    let public_values = sp1_proof_with_public_values.public_values.as_slice();
+
+    // The fixture written by the `evm` script, with the outputs of the Fibonacci program.
+    let word =
+        |i: usize| u32::from_be_bytes(public_values[32 * i + 28..32 * (i + 1)].try_into().unwrap());
+    let json = serde_json::to_string_pretty(&serde_json::json!({
+        "a": word(1),
+        "b": word(2),
+        "n": word(0),
+        "vkey": artifacts.vkey_hash_hex(),
+        "publicValues": format!("0x{}", hex::encode(public_values)),
+        "proof": format!("0x{}", hex::encode(sp1_proof_with_public_values.bytes())),
+    }))
+    .unwrap();
+    let fixture = Sp1EvmFixture::from_json(&json).unwrap();
+    assert_eq!(fixture.public_values, public_values);
+    assert_eq!(fixture.proof, sp1_proof_with_public_values.bytes());
+    assert_eq!(
+        Sp1EvmFixture::load("../proofs/fibonacci_fixture.json").unwrap(),
+        fixture
+    );
+    assert_eq!(fixture.proof, FIBONACCI_PROOF);
+    assert_eq!(
+        Sp1EvmFixture::from_json(&fixture.to_json()).unwrap(),
+        fixture
+    );
+
+    // The fixture converts to the same artifacts as the SP1 proof.
+    assert_eq!(fixture.convert().unwrap(), artifacts);
+    assert_eq!(
+        fixture
+            .convert_with_groth16_vk(GROTH16_VK_4_0_0_RC3_BYTES)
+            .unwrap(),
+        artifacts
+    );
+    assert!(matches!(
+        fixture.convert_with_groth16_vk(GROTH16_VK_3_0_0_BYTES),
+        Err(ConvertError::VerifyingKeyMismatch { .. })
+    ));
+
+    // Fixtures of mock proofs convert only as mock proofs.
+    let mock_fixture = Sp1EvmFixture {
+        proof: Vec::new(),
+        ..fixture.clone()
+    };
+    assert!(matches!(
+        mock_fixture.convert(),
+        Err(ConvertError::MockProof)
+    ));
+    assert!(mock_fixture.convert_mock().unwrap().mock);
+    assert!(matches!(
+        fixture.convert_mock(),
+        Err(ConvertError::NotMockProof)
+    ));
+
+    let dir = std::env::temp_dir().join(format!("sp1-sui-evm-fixture-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("groth16-fixture.json");
+    std::fs::write(&path, &json).unwrap();
+    assert_eq!(Sp1EvmFixture::load(&path).unwrap(), fixture);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(matches!(
+        Sp1EvmFixture::load(&path),
+        Err(EvmFixtureError::Io { .. })
+    ));
+
+    // Hex fields must decode, and public values that do not match the proof are rejected.
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["proof"] = "0xzz".into();
+    assert!(matches!(
+        Sp1EvmFixture::from_json(&value.to_string()),
+        Err(EvmFixtureError::Json(_))
+    ));
+    let mut tampered = fixture.clone();
+    tampered.public_values[31] ^= 1;
+    assert!(tampered.convert().is_err());
+}
+
+#[test]
+fn test_select_groth16_vk() {
+    let proof_bytes = FIBONACCI_PROOF.to_vec();
+
+    let (version, groth16_vk) = select_groth16_vk(&proof_bytes).unwrap();
+    assert_eq!(version, "v4.0.0-rc.3");
+    assert_eq!(groth16_vk, GROTH16_VK_4_0_0_RC3_BYTES);
+
+    let mut unknown_prefix = proof_bytes.clone();
+    unknown_prefix[..4].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    assert!(matches!(
+        select_groth16_vk(&unknown_prefix),
+        Err(ConvertError::UnsupportedSp1Version([
+            0xde, 0xad, 0xbe, 0xef
+        ]))
+    ));
+}
+
+#[test]
+fn test_sp1_version_registry() {
+    use crate::{
+        GROTH16_VK_2_0_0_BYTES, GROTH16_VK_3_0_0_RC4_BYTES, GROTH16_VK_REGISTRY,
+        ParseSp1VersionError, Sp1Version, groth16_vk_hash_prefix, select_groth16_vk,
+        supported_versions,
+    };
+
+    assert_eq!(
+        supported_versions()
+            .iter()
+            .map(Sp1Version::to_string)
+            .collect::<Vec<_>>(),
+        ["v4.0.0-rc.3", "v3.0.0", "v3.0.0rc4", "v2.0.0"]
+    );
+    assert_eq!(Sp1Version::latest(), Sp1Version::V4_0_0Rc3);
+    assert_eq!(GROTH16_VK_REGISTRY.len(), supported_versions().len());
+    for (version, groth16_vk, prefix) in GROTH16_VK_REGISTRY {
+        assert_eq!(*groth16_vk, version.groth16_vk());
+        assert_eq!(*prefix, groth16_vk_hash_prefix(groth16_vk));
+        assert_eq!(version.as_str().parse::<Sp1Version>(), Ok(*version));
+        assert_eq!(
+            Sp1Version::from_groth16_vk_hash_prefix(*prefix),
+            Some(*version)
+        );
+        assert_eq!(Sp1Version::from_groth16_vk(groth16_vk), Some(*version));
+
+        // Every registered key is selected by the prefix of its proofs.
+        let mut proof_bytes = prefix.to_vec();
+        proof_bytes.extend([0; 256]);
+        assert_eq!(
+            select_groth16_vk(&proof_bytes).unwrap(),
+            (version.as_str(), *groth16_vk)
+        );
+    }
+    // The generated constants name the keys of the registry.
+    assert_eq!(
+        GROTH16_VK_4_0_0_RC3_BYTES,
+        Sp1Version::V4_0_0Rc3.groth16_vk()
+    );
+    assert_eq!(GROTH16_VK_3_0_0_BYTES, Sp1Version::V3_0_0.groth16_vk());
+    assert_eq!(
+        GROTH16_VK_3_0_0_RC4_BYTES,
+        Sp1Version::V3_0_0Rc4.groth16_vk()
+    );
+    assert_eq!(GROTH16_VK_2_0_0_BYTES, Sp1Version::V2_0_0.groth16_vk());
+    assert_eq!(
+        GROTH16_VK_REGISTRY[0].2,
+        [0x11, 0xb6, 0xa0, 0x9d],
+        "v4.0.0-rc.3 prefix"
+    );
+
+    assert_eq!("3.0.0rc4".parse(), Ok(Sp1Version::V3_0_0Rc4));
+    assert_eq!(
+        "v4.0.0".parse::<Sp1Version>(),
+        Err(ParseSp1VersionError("v4.0.0".to_string()))
+    );
+}
+
+#[test]
+fn test_public_input_schema() {
+    use crate::ark_converter::{load_ark_public_inputs, load_ark_public_inputs_from_bytes};
+    use crate::gnark::GnarkGroth16VerifyingKey;
+    use crate::{
+        Sp1PublicInput, Sp1Version, ValidationMode, convert_parts, sp1_public_input_schema,
+    };
+
+    for version in crate::supported_versions() {
+        assert_eq!(
+            version.public_input_schema(),
+            [
+                Sp1PublicInput::VkeyHash,
+                Sp1PublicInput::CommittedValuesDigest
+            ]
+        );
+    }
+    assert_eq!(
+        sp1_public_input_schema(2),
+        Some(Sp1Version::latest().public_input_schema())
+    );
+    assert_eq!(
+        sp1_public_input_schema(5)
+            .unwrap()
+            .iter()
+            .map(|input| input.name())
+            .collect::<Vec<_>>(),
+        [
+            "vkey_hash",
+            "committed_values_digest",
+            "exit_code",
+            "vk_root",
+            "proof_nonce"
+        ]
+    );
+    assert_eq!(sp1_public_input_schema(3), None);
+
+    let artifacts = fibonacci_artifacts();
+    assert_eq!(artifacts.public_input_schema(), sp1_public_input_schema(2));
+    let inputs = [artifacts.vkey_hash, artifacts.committed_values_digest];
+    assert_eq!(
+        load_ark_public_inputs(&inputs),
+        load_ark_public_inputs_from_bytes(&inputs[0], &inputs[1])
+    );
+
+    // The number of public inputs is driven by the verifying key.
+    let proof_bytes = FIBONACCI_PROOF.to_vec();
+    let gnark_vk = GnarkGroth16VerifyingKey::from_bytes(GROTH16_VK_4_0_0_RC3_BYTES).unwrap();
+    assert_eq!(gnark_vk.num_public_inputs(), 2);
+    let convert = |inputs: Vec<[u8; 32]>| {
+        convert_parts(
+            &proof_bytes[4..],
+            artifacts.public_values.clone(),
+            inputs,
+            "v4.0.0-rc.3",
+            &gnark_vk,
+            ValidationMode::default(),
+        )
+    };
+    assert_eq!(convert(inputs.to_vec()).unwrap(), artifacts);
+    assert!(matches!(
+        convert(vec![inputs[0], inputs[1], [0; 32]]),
+        Err(ConvertError::PublicInputCountMismatch {
+            expected: 2,
+            actual: 3
+        })
+    ));
+}
+
+#[test]
+fn test_convert_raw_extra_public_inputs() {
+    use ark_bn254::{Fr, G1Affine, G1Projective};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::PrimeField;
+
+    use crate::gnark::GnarkGroth16VerifyingKey;
+    use crate::groth16_vk_hash_prefix;
+
+    let proof_bytes = FIBONACCI_PROOF.to_vec();
+    let artifacts = fibonacci_artifacts();
+
+    // Turn the SP1 verifying key into the key of a wrapper circuit with the exit code, vk root
+    // and proof nonce as extra public inputs, for which the SP1 proof is valid with extra keys
+    // K[3..6]: K[0]' + K[3] * exit_code + K[4] * vk_root + K[5] * proof_nonce = K[0].
+    let extra_inputs = [[0; 32], [7; 32], [9; 32]];
+    let mut gnark_vk: GnarkGroth16VerifyingKey =
+        GnarkGroth16VerifyingKey::from_bytes(GROTH16_VK_4_0_0_RC3_BYTES).unwrap();
+    for (i, input) in extra_inputs.iter().enumerate() {
+        let k = (G1Affine::generator() * Fr::from(i as u64 + 17)).into_affine();
+        let input = Fr::from_be_bytes_mod_order(input);
+        gnark_vk.g1_k[0] = (G1Projective::from(gnark_vk.g1_k[0]) - k * input).into_affine();
+        gnark_vk.g1_k.push(k);
+    }
+    assert_eq!(gnark_vk.num_public_inputs(), 5);
+    let groth16_vk = gnark_vk.to_bytes();
+
+    // The extra public inputs come between the prefix and the gnark proof.
+    let extended_proof_bytes = [
+        &groth16_vk_hash_prefix(&groth16_vk)[..],
+        &extra_inputs.concat(),
+        &proof_bytes[4..],
+    ]
+    .concat();
+    let extended = convert_raw(
+        &extended_proof_bytes,
+        &artifacts.public_values,
+        &artifacts.vkey_hash_hex(),
+        &groth16_vk,
+    )
+    .unwrap();
+    assert_eq!(extended.sp1_version, "custom");
+    assert_eq!(extended.public_input_schema(), None);
+    assert_eq!(extended.proof_points, artifacts.proof_points);
+    assert_eq!(extended.public_inputs[..64], artifacts.public_inputs);
+    assert_eq!(extended.public_inputs.len(), 5 * 32);
+    assert_eq!(
+        extended.verify().unwrap().public_inputs[3],
+        Fr::from_be_bytes_mod_order(&[7; 32])
+    );
+
+    // Other extra public inputs do not verify, and truncated proofs are rejected.
+    let mut other_inputs = extended_proof_bytes.clone();
+    other_inputs[4 + 32 + 31] ^= 1;
+    assert!(matches!(
+        convert_raw(
+            &other_inputs,
+            &artifacts.public_values,
+            &artifacts.vkey_hash_hex(),
+            &groth16_vk
+        ),
+        Err(ConvertError::VerificationFailed)
+    ));
+    assert!(matches!(
+        convert_raw(
+            &extended_proof_bytes[..4 + 64],
+            &artifacts.public_values,
+            &artifacts.vkey_hash_hex(),
+            &groth16_vk
+        ),
+        Err(ConvertError::InvalidProof(_))
+    ));
+}
+
+#[test]
+fn test_load_groth16_vk() {
+    use crate::{GROTH16_VK_3_0_0_BYTES, load_groth16_vk};
+
+    let dir = std::env::temp_dir().join(format!("sp1-sui-vk-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let vk_path = dir.join("groth16_vk.bin");
+    std::fs::write(&vk_path, GROTH16_VK_4_0_0_RC3_BYTES).unwrap();
+    let groth16_vk = load_groth16_vk(&vk_path).unwrap();
+    assert_eq!(groth16_vk, GROTH16_VK_4_0_0_RC3_BYTES);
+    assert_eq!(
+        convert_raw(
+            &FIBONACCI_PROOF,
+            &FIBONACCI_PUBLIC_VALUES,
+            FIBONACCI_VKEY_HASH,
+            &groth16_vk
+        )
+        .unwrap(),
+        fibonacci_artifacts()
+    );
+    #[cfg(feature = "sp1-sdk")]
+    {
+        use crate::try_convert_with_groth16_vk;
+
+        let sp1_proof_with_public_values = fibonacci_proof();
+        assert_eq!(
+            try_convert_with_groth16_vk(&sp1_proof_with_public_values, &groth16_vk).unwrap(),
+            try_convert(&sp1_proof_with_public_values).unwrap()
+        );
+        assert!(matches!(
+            try_convert_with_groth16_vk(&sp1_proof_with_public_values, GROTH16_VK_3_0_0_BYTES),
+            Err(ConvertError::VerifyingKeyMismatch { .. })
+        ));
+    }
+
+    // The key must match the proof.
+    assert!(matches!(
+        convert_raw(
+            &FIBONACCI_PROOF,
+            &FIBONACCI_PUBLIC_VALUES,
+            FIBONACCI_VKEY_HASH,
+            GROTH16_VK_3_0_0_BYTES
+        ),
+        Err(ConvertError::VerifyingKeyMismatch { .. })
+    ));
+
+    // Keys are validated when loaded.
+    std::fs::write(&vk_path, &GROTH16_VK_4_0_0_RC3_BYTES[..300]).unwrap();
+    assert!(matches!(
+        load_groth16_vk(&vk_path),
+        Err(ConvertError::InvalidVerifyingKey(_))
+    ));
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(matches!(
+        load_groth16_vk(&vk_path),
+        Err(ConvertError::VerifyingKeyIo { .. })
+    ));
+}
+
+#[test]
+fn test_load_embedded_verifying_keys() {
+    use crate::{GROTH16_VK_REGISTRY, ark_converter::load_ark_groth16_verifying_key_from_bytes};
+
+    for (version, groth16_vk, _) in GROTH16_VK_REGISTRY {
+        let vk = load_ark_groth16_verifying_key_from_bytes(groth16_vk)
+            .unwrap_or_else(|e| panic!("{version}: {e}"));
+        assert_eq!(vk.gamma_abc_g1.len(), 3);
+    }
+}
+
+#[test]
+fn test_load_malformed_verifying_key() {
+    use crate::ark_converter::load_ark_groth16_verifying_key_from_bytes;
+
+    let error = |bytes: &[u8]| {
+        load_ark_groth16_verifying_key_from_bytes(bytes)
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert_eq!(
+        error(&GROTH16_VK_4_0_0_RC3_BYTES[..100]),
+        "G2.Beta at offset 64: unexpected end of input"
+    );
+
+    let mut huge_count = GROTH16_VK_4_0_0_RC3_BYTES.to_vec();
+    huge_count[288..292].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert_eq!(
+        error(&huge_count),
+        "len(K) at offset 288: length exceeds input size"
+    );
+
+    let mut not_on_curve = GROTH16_VK_4_0_0_RC3_BYTES.to_vec();
+    not_on_curve[388 - 32 + 31] ^= 1;
+    assert_eq!(error(&not_on_curve), "K[2] at offset 356: not on curve");
+
+    let mut trailing = GROTH16_VK_4_0_0_RC3_BYTES.to_vec();
+    trailing.push(0);
+    assert_eq!(
+        error(&trailing),
+        "end of buffer at offset 396: unexpected trailing bytes"
+    );
+}
+
+#[test]
+fn test_gnark_verifying_key_roundtrip() {
+    use ark_groth16::VerifyingKey;
+
+    use crate::{
+        GROTH16_VK_2_0_0_BYTES, GROTH16_VK_REGISTRY,
+        gnark::{GnarkCommitmentKeyLayout, GnarkGroth16VerifyingKey},
+    };
+
+    for (version, groth16_vk, _) in GROTH16_VK_REGISTRY {
+        let gnark_vk = GnarkGroth16VerifyingKey::from_bytes(groth16_vk).unwrap();
+        assert_eq!(gnark_vk.to_bytes(), *groth16_vk, "{version}");
+        assert!(!gnark_vk.has_commitments());
+
+        let ark_vk = VerifyingKey::<Bn254>::from(gnark_vk.clone());
+        let from_ark = GnarkGroth16VerifyingKey::from(&ark_vk);
+        assert_eq!(VerifyingKey::<Bn254>::from(from_ark), ark_vk);
+    }
+
+    let legacy: GnarkGroth16VerifyingKey =
+        GnarkGroth16VerifyingKey::from_bytes(GROTH16_VK_2_0_0_BYTES).unwrap();
+    assert_eq!(
+        legacy.commitment_key_layout,
+        GnarkCommitmentKeyLayout::Single
+    );
+    assert_eq!(legacy.commitment_keys.len(), 1);
+}
+
+#[test]
+fn test_strict_validation() {
+    use ark_bn254::{Fq, Fq2, G2Affine};
+    use ark_ff::{BigInteger, PrimeField};
+
+    use crate::{
+        GROTH16_VK_REGISTRY, ValidationMode,
+        ark_converter::{
+            ArkGroth16Error, compress_g2, load_ark_groth16_verifying_key_from_bytes,
+            load_ark_groth16_verifying_key_from_bytes_with_mode,
+            load_ark_proof_from_bytes_with_mode,
+        },
+        convert_raw_with_mode,
+    };
+
+    assert!(
+        convert_raw_with_mode(
+            &FIBONACCI_PROOF,
+            &FIBONACCI_PUBLIC_VALUES,
+            FIBONACCI_VKEY_HASH,
+            GROTH16_VK_4_0_0_RC3_BYTES,
+            ValidationMode::Strict
+        )
+        .is_ok()
+    );
+    #[cfg(feature = "sp1-sdk")]
+    assert!(crate::try_convert_with_mode(&fibonacci_proof(), ValidationMode::Strict).is_ok());
+    for (version, groth16_vk, _) in GROTH16_VK_REGISTRY {
+        load_ark_groth16_verifying_key_from_bytes_with_mode(groth16_vk, ValidationMode::Strict)
+            .unwrap_or_else(|e| panic!("{version}: {e}"));
+    }
+
+    // x = p is rejected as a non-canonical encoding.
+    let modulus = Fq::MODULUS.to_bytes_be();
+    let mut non_canonical_vk = GROTH16_VK_4_0_0_RC3_BYTES.to_vec();
+    non_canonical_vk[292..324].copy_from_slice(&modulus);
+    non_canonical_vk[292] |= 0b10 << 6;
+    assert_eq!(
+        load_ark_groth16_verifying_key_from_bytes_with_mode(
+            &non_canonical_vk,
+            ValidationMode::Strict
+        )
+        .unwrap_err()
+        .to_string(),
+        "K[0] at offset 292: non-canonical field encoding"
+    );
+
+    let mut non_canonical_proof = FIBONACCI_PROOF[4..].to_vec();
+    non_canonical_proof[..32].copy_from_slice(&modulus);
+    assert!(matches!(
+        load_ark_proof_from_bytes_with_mode(&non_canonical_proof, ValidationMode::Strict),
+        Err(ArkGroth16Error::NonCanonicalEncoding)
+    ));
+
+    // A point on the twist outside of the prime order subgroup is only rejected in strict mode.
+    let not_in_subgroup = (1u64..)
+        .filter_map(|i| {
+            G2Affine::get_point_from_x_unchecked(Fq2::new(Fq::from(i), Fq::from(0u64)), false)
+        })
+        .find(|point| !point.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    let mut bad_vk = GROTH16_VK_4_0_0_RC3_BYTES.to_vec();
+    bad_vk[64..128].copy_from_slice(&compress_g2(&not_in_subgroup));
+    assert!(load_ark_groth16_verifying_key_from_bytes(&bad_vk).is_ok());
+    assert_eq!(
+        load_ark_groth16_verifying_key_from_bytes_with_mode(&bad_vk, ValidationMode::Strict)
+            .unwrap_err()
+            .to_string(),
+        "G2.Beta at offset 64: not in subgroup"
+    );
+}
+
+#[test]
+fn test_convert_ark_to_gnark() {
+    use ark_bn254::{G1Affine, G2Affine};
+    use ark_ec::AffineRepr;
+    use ark_groth16::{Proof, VerifyingKey};
+    use ark_serialize::CanonicalDeserialize;
+
+    use crate::{
+        ark_converter::{
+            ark_decompressed_g1_to_gnark_decompressed_g1,
+            ark_decompressed_g2_to_gnark_decompressed_g2, ark_groth16_verifying_key_to_gnark_bytes,
+            ark_proof_to_gnark_bytes, compress_g1, compress_g2, decompress_g1, decompress_g2,
+            gnark_decompressed_g1_to_ark_decompressed_g1,
+            gnark_decompressed_g2_to_ark_decompressed_g2,
+            load_ark_groth16_verifying_key_from_bytes, load_ark_proof_from_bytes,
+        },
+        ark_proof_to_sp1_proof_bytes,
+    };
+
+    let proof_bytes = FIBONACCI_PROOF.to_vec();
+    let artifacts = fibonacci_artifacts();
+
+    // Sui proof points back to the SP1 proof bytes.
+    let ark_proof = Proof::<Bn254>::deserialize_compressed(&*artifacts.proof_points).unwrap();
+    assert_eq!(ark_proof_to_gnark_bytes(&ark_proof), proof_bytes[4..]);
+    assert_eq!(
+        load_ark_proof_from_bytes(&proof_bytes[4..]).unwrap(),
+        ark_proof
+    );
+
+    let sp1_proof_bytes = ark_proof_to_sp1_proof_bytes(&ark_proof, GROTH16_VK_4_0_0_RC3_BYTES);
+    assert_eq!(sp1_proof_bytes, proof_bytes);
+    // The same bytes as the proof serialized by the SP1 SDK.
+    #[cfg(feature = "sp1-sdk")]
+    {
+        let sp1_proof_with_public_values = fibonacci_proof();
+        let ark_proof = Proof::<Bn254>::deserialize_compressed(
+            &*try_convert(&sp1_proof_with_public_values)
+                .unwrap()
+                .proof_points,
+        )
+        .unwrap();
+        assert_eq!(
+            ark_proof_to_sp1_proof_bytes(&ark_proof, GROTH16_VK_4_0_0_RC3_BYTES),
+            sp1_proof_with_public_values.bytes()
+        );
+    }
+    assert!(
+        Groth16Verifier::verify(
+            &sp1_proof_bytes,
+            &artifacts.public_values,
+            &artifacts.vkey_hash_hex(),
+            GROTH16_VK_4_0_0_RC3_BYTES,
+        )
+        .is_ok()
+    );
+
+    // Sui verifying key back to the gnark format.
+    let ark_vk = VerifyingKey::<Bn254>::deserialize_compressed(&*artifacts.verifying_key).unwrap();
+    let gnark_vk = ark_groth16_verifying_key_to_gnark_bytes(&ark_vk);
+    assert_eq!(
+        load_ark_groth16_verifying_key_from_bytes(&gnark_vk).unwrap(),
+        ark_vk
+    );
+    assert_eq!(gnark_vk[..32], GROTH16_VK_4_0_0_RC3_BYTES[..32]);
+    assert_eq!(gnark_vk[64..192], GROTH16_VK_4_0_0_RC3_BYTES[64..192]);
+    assert_eq!(gnark_vk[224..], GROTH16_VK_4_0_0_RC3_BYTES[224..]);
+
+    // Individual points, including the point at infinity.
+    for g1 in [ark_proof.a, ark_proof.c, -ark_proof.a, G1Affine::zero()] {
+        assert_eq!(decompress_g1(&compress_g1(&g1)).unwrap(), g1);
+        let decompressed = ark_decompressed_g1_to_gnark_decompressed_g1(&g1);
+        assert_eq!(
+            gnark_decompressed_g1_to_ark_decompressed_g1(&decompressed).unwrap(),
+            g1
+        );
+    }
+    for g2 in [ark_proof.b, -ark_proof.b, G2Affine::zero()] {
+        assert_eq!(decompress_g2(&compress_g2(&g2)).unwrap(), g2);
+        let decompressed = ark_decompressed_g2_to_gnark_decompressed_g2(&g2);
+        assert_eq!(
+            gnark_decompressed_g2_to_ark_decompressed_g2(&decompressed).unwrap(),
+            g2
+        );
+    }
+}
+
+#[test]
+fn test_bsb22_commitment() {
+    use ark_bn254::{Fr, G1Affine, G1Projective, G2Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+
+    use crate::ark_converter::load_ark_public_inputs;
+    use crate::commitment::expand_message_xmd;
+    use crate::gnark::{GnarkGroth16Proof, GnarkGroth16VerifyingKey, GnarkPedersenVerifyingKey};
+    use crate::{
+        CommitmentError, bsb22_commitment_hash, fold_bsb22_commitment, groth16_vk_hash_prefix,
+    };
+
+    // RFC 9380, K.1: expand_message_xmd(SHA-256).
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    assert_eq!(
+        hex::encode(expand_message_xmd(b"", dst, 32)),
+        "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+    );
+    assert_eq!(
+        hex::encode(expand_message_xmd(b"abc", dst, 32)),
+        "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+    );
+
+    let proof_bytes = FIBONACCI_PROOF.to_vec();
+    let artifacts = fibonacci_artifacts();
+    let public_inputs =
+        load_ark_public_inputs(&[artifacts.vkey_hash, artifacts.committed_values_digest]);
+
+    // Turn the SP1 verifying key into the key of a circuit committing to the committed values
+    // digest, for which the SP1 proof is valid with the commitment C and an extra key K[3]:
+    // K[0]' + K[3] * hash + C = K[0].
+    let sigma = Fr::from(5u64);
+    let g = (G2Affine::generator() * Fr::from(11u64)).into_affine();
+    let commitment = (G1Affine::generator() * Fr::from(3u64)).into_affine();
+    let hash = bsb22_commitment_hash(&commitment, &public_inputs[1..]);
+    let k_3 = (G1Affine::generator() * Fr::from(13u64)).into_affine();
+    let mut gnark_vk = GnarkGroth16VerifyingKey::from_bytes(GROTH16_VK_4_0_0_RC3_BYTES).unwrap();
+    gnark_vk.g1_k[0] =
+        (G1Projective::from(gnark_vk.g1_k[0]) - commitment - k_3 * hash).into_affine();
+    gnark_vk.g1_k.push(k_3);
+    gnark_vk.public_and_commitment_committed = vec![vec![2]];
+    gnark_vk.commitment_keys = vec![GnarkPedersenVerifyingKey {
+        g,
+        g_sigma_neg: (g * -sigma).into_affine(),
+    }];
+    let groth16_vk = gnark_vk.to_bytes();
+    assert!(gnark_vk.has_commitments());
+
+    let mut gnark_proof = GnarkGroth16Proof::from_bytes(&proof_bytes[4..]).unwrap();
+    assert!(!gnark_proof.has_commitments());
+    assert_eq!(gnark_proof.to_bytes(), proof_bytes[4..]);
+    gnark_proof.commitments = vec![commitment];
+    gnark_proof.commitment_pok = (commitment * sigma).into_affine();
+    assert_eq!(
+        GnarkGroth16Proof::from_bytes(&gnark_proof.to_bytes()).unwrap(),
+        gnark_proof
+    );
+
+    let convert = |gnark_proof: &GnarkGroth16Proof| {
+        let proof_bytes = [
+            &groth16_vk_hash_prefix(&groth16_vk)[..],
+            &gnark_proof.to_bytes(),
+        ]
+        .concat();
+        convert_raw(
+            &proof_bytes,
+            &artifacts.public_values,
+            &artifacts.vkey_hash_hex(),
+            &groth16_vk,
+        )
+    };
+
+    // The commitment is folded into a proof-specific verifying key, and its hash is appended to
+    // the public inputs.
+    let folded = convert(&gnark_proof).unwrap();
+    assert_eq!(folded.sp1_version, "custom");
+    assert_eq!(folded.proof_points, artifacts.proof_points);
+    assert_eq!(folded.public_inputs[..64], artifacts.public_inputs);
+    assert_eq!(folded.public_inputs.len(), 96);
+    assert_ne!(folded.verifying_key, artifacts.verifying_key);
+    assert_eq!(folded.verify().unwrap().public_inputs[2], hash);
+
+    let mut invalid_pok = gnark_proof.clone();
+    invalid_pok.commitment_pok = commitment;
+    assert!(matches!(
+        convert(&invalid_pok),
+        Err(ConvertError::Commitment(
+            CommitmentError::InvalidProofOfKnowledge
+        ))
+    ));
+    let mut invalid_commitment = gnark_proof.clone();
+    invalid_commitment.commitments[0] = (G1Affine::generator() * Fr::from(4u64)).into_affine();
+    assert!(matches!(
+        convert(&invalid_commitment),
+        Err(ConvertError::Commitment(
+            CommitmentError::InvalidProofOfKnowledge
+        ))
+    ));
+    let mut missing_commitment = gnark_proof.clone();
+    missing_commitment.commitments.clear();
+    assert!(matches!(
+        convert(&missing_commitment),
+        Err(ConvertError::Commitment(CommitmentError::CountMismatch {
+            expected: 1,
+            actual: 0
+        }))
+    ));
+
+    // Only a single commitment is supported.
+    let mut two_commitments = gnark_vk.clone();
+    two_commitments
+        .public_and_commitment_committed
+        .push(vec![1]);
+    assert_eq!(
+        fold_bsb22_commitment(&two_commitments, &gnark_proof, &public_inputs),
+        Err(CommitmentError::Unsupported(2))
+    );
+}
+
+#[test]
+fn test_convert_gnark_groth16() {
+    use ark_bls12_381::{Bls12_381, Fr, G1Affine, G2Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{BigInteger, PrimeField};
+    use ark_groth16::{Proof, VerifyingKey};
+
+    use crate::gnark::{GnarkGroth16Proof, GnarkGroth16VerifyingKey};
+    use crate::{SuiCurve, convert_gnark_groth16, load_gnark_public_witness};
+
+    /// A gnark witness with the given public and secret variables.
+    fn witness<F: PrimeField>(public: &[F], secret: &[F]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(public.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&(secret.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(&((public.len() + secret.len()) as u32).to_be_bytes());
+        for scalar in public.iter().chain(secret) {
+            bytes.extend_from_slice(&scalar.into_bigint().to_bytes_be());
+        }
+        bytes
+    }
+
+    assert_eq!("bls12-381".parse::<SuiCurve>(), Ok(SuiCurve::Bls12381));
+    assert_eq!("BN254".parse::<SuiCurve>(), Ok(SuiCurve::Bn254));
+    assert!("bls12-377".parse::<SuiCurve>().is_err());
+
+    // The gnark proof inside an SP1 proof converts to the same artifacts over BN254, in both
+    // proof encodings.
+    let proof_bytes = FIBONACCI_PROOF.to_vec();
+    let artifacts = fibonacci_artifacts();
+    let public_witness = witness(
+        &crate::ark_converter::load_ark_public_inputs(&[
+            artifacts.vkey_hash,
+            artifacts.committed_values_digest,
+        ]),
+        &[],
+    );
+    let gnark_proof: GnarkGroth16Proof = GnarkGroth16Proof::from_bytes(&proof_bytes[4..]).unwrap();
+    for proof in [proof_bytes[4..].to_vec(), gnark_proof.to_compressed_bytes()] {
+        let circuit = convert_gnark_groth16(
+            SuiCurve::Bn254,
+            GROTH16_VK_4_0_0_RC3_BYTES,
+            &proof,
+            &public_witness,
+        )
+        .unwrap();
+        assert_eq!(circuit.curve, SuiCurve::Bn254);
+        assert_eq!(circuit.verifying_key, artifacts.verifying_key);
+        assert_eq!(circuit.public_inputs, artifacts.public_inputs);
+        assert_eq!(circuit.proof_points, artifacts.proof_points);
+    }
+
+    // A BLS12-381 proof of two public inputs x, satisfying
+    // a * b = alpha * beta + (k_0 + k_1 * x_1 + k_2 * x_2) * gamma + c * delta.
+    let [alpha, beta, gamma, delta, a, b] = [2u64, 3, 5, 7, 11, 13].map(Fr::from);
+    let k = [17u64, 19, 23].map(Fr::from);
+    let inputs = [Fr::from(29u64), -Fr::from(31u64)];
+    let l = k[0] + k[1] * inputs[0] + k[2] * inputs[1];
+    let c = (a * b - alpha * beta - l * gamma) / delta;
+    let g1 = |scalar: Fr| (G1Affine::generator() * scalar).into_affine();
+    let g2 = |scalar: Fr| (G2Affine::generator() * scalar).into_affine();
+    let ark_vk = VerifyingKey::<Bls12_381> {
+        alpha_g1: g1(alpha),
+        beta_g2: g2(beta),
+        gamma_g2: g2(gamma),
+        delta_g2: g2(delta),
+        gamma_abc_g1: k.map(g1).to_vec(),
+    };
+    let gnark_vk = GnarkGroth16VerifyingKey::from(&ark_vk);
+    let groth16_vk = gnark_vk.to_bytes();
+    assert_eq!(groth16_vk.len(), 48 + 48 + 96 * 3 + 48 + 4 + 48 * 3 + 4 + 4);
+    assert_eq!(
+        GnarkGroth16VerifyingKey::<Bls12_381>::from_bytes(&groth16_vk).unwrap(),
+        gnark_vk
+    );
+    let gnark_proof = GnarkGroth16Proof::<Bls12_381> {
+        ar: g1(a),
+        bs: g2(b),
+        krs: g1(c),
+        commitments: Vec::new(),
+        commitment_pok: G1Affine::zero(),
+    };
+    let public_witness = witness(&inputs, &[Fr::from(37u64)]);
+    assert_eq!(
+        load_gnark_public_witness::<Fr>(&public_witness).unwrap(),
+        inputs
+    );
+
+    for proof in [gnark_proof.to_bytes(), gnark_proof.to_compressed_bytes()] {
+        let circuit =
+            convert_gnark_groth16(SuiCurve::Bls12381, &groth16_vk, &proof, &public_witness)
+                .unwrap();
+        assert_eq!(circuit.curve, SuiCurve::Bls12381);
+        circuit.verify().unwrap();
+
+        let mut vk_bytes = Vec::new();
+        ark_vk.serialize_compressed(&mut vk_bytes).unwrap();
+        assert_eq!(circuit.verifying_key, vk_bytes);
+        let mut proof_points = Vec::new();
+        Proof::from(&gnark_proof)
+            .serialize_compressed(&mut proof_points)
+            .unwrap();
+        assert_eq!(circuit.proof_points, proof_points);
+        assert_eq!(circuit.proof_points.len(), 48 + 96 + 48);
+        assert_eq!(
+            circuit.public_inputs[..32],
+            inputs[0].into_bigint().to_bytes_le()
+        );
+        assert_eq!(circuit.public_inputs.len(), 64);
+    }
+
+    // The proof does not verify for other public inputs, and does not parse as a BN254 proof.
+    let proof = gnark_proof.to_bytes();
+    let other_witness = witness(&[inputs[1], inputs[0]], &[]);
+    assert!(matches!(
+        convert_gnark_groth16(SuiCurve::Bls12381, &groth16_vk, &proof, &other_witness),
+        Err(ConvertError::VerificationFailed)
+    ));
+    assert!(matches!(
+        convert_gnark_groth16(
+            SuiCurve::Bls12381,
+            &groth16_vk,
+            &proof,
+            &witness(&inputs[..1], &[])
+        ),
+        Err(ConvertError::PublicInputCountMismatch {
+            expected: 2,
+            actual: 1
+        })
+    ));
+    assert!(matches!(
+        convert_gnark_groth16(SuiCurve::Bn254, &groth16_vk, &proof, &public_witness),
+        Err(ConvertError::InvalidProof(_))
+    ));
+
+    // Witness scalars must be canonical, and the vector must hold every variable.
+    let mut non_canonical = witness(&inputs, &[]);
+    non_canonical[12..44].copy_from_slice(&Fr::MODULUS.to_bytes_be());
+    assert!(matches!(
+        convert_gnark_groth16(SuiCurve::Bls12381, &groth16_vk, &proof, &non_canonical),
+        Err(ConvertError::InvalidPublicWitness(_))
+    ));
+    let mut truncated = witness(&inputs, &[]);
+    truncated[11] = 1;
+    assert!(load_gnark_public_witness::<Fr>(&truncated).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_convert_snarkjs_groth16() {
+    use ark_bn254::Fr;
+    use ark_ff::{BigInteger, PrimeField};
+
+    use crate::{
+        SnarkjsError, SuiCurve, convert_snarkjs_groth16, convert_snarkjs_groth16_files,
+        parse_snarkjs_proof, parse_snarkjs_verifying_key, verify_sui_artifacts,
+    };
+
+    // Multiplier2 circuit, `c <== a * b` with a = 3 and b = 11, in the snarkjs JSON formats.
+    let dir = "../proofs/snarkjs";
+    let vk_json = std::fs::read_to_string(format!("{dir}/verification_key.json")).unwrap();
+    let proof_json = std::fs::read_to_string(format!("{dir}/proof.json")).unwrap();
+    let public_json = std::fs::read_to_string(format!("{dir}/public.json")).unwrap();
+
+    let artifacts = convert_snarkjs_groth16_files(
+        format!("{dir}/verification_key.json"),
+        format!("{dir}/proof.json"),
+        format!("{dir}/public.json"),
+    )
+    .unwrap();
+    assert_eq!(artifacts.curve, SuiCurve::Bn254);
+    artifacts.verify().unwrap();
+    verify_sui_artifacts(
+        &artifacts.verifying_key,
+        &artifacts.public_inputs,
+        &artifacts.proof_points,
+    )
+    .unwrap();
+
+    // The artifacts use the same serialization as SP1 proofs.
+    let mut vk_bytes = Vec::new();
+    parse_snarkjs_verifying_key(&vk_json)
+        .unwrap()
+        .serialize_compressed(&mut vk_bytes)
+        .unwrap();
+    assert_eq!(artifacts.verifying_key, vk_bytes);
+    let mut proof_points = Vec::new();
+    parse_snarkjs_proof(&proof_json)
+        .unwrap()
+        .serialize_compressed(&mut proof_points)
+        .unwrap();
+    assert_eq!(artifacts.proof_points, proof_points);
+    assert_eq!(
+        artifacts.public_inputs,
+        Fr::from(33u64).into_bigint().to_bytes_le()
+    );
+
+    // The proof does not verify for other public signals.
+    assert!(matches!(
+        convert_snarkjs_groth16(&vk_json, &proof_json, r#"["34"]"#),
+        Err(ConvertError::VerificationFailed)
+    ));
+    assert!(matches!(
+        convert_snarkjs_groth16(&vk_json, &proof_json, r#"["33", "1"]"#),
+        Err(ConvertError::PublicInputCountMismatch {
+            expected: 1,
+            actual: 2
+        })
+    ));
+
+    // Public signals and coordinates must be canonical decimals.
+    let modulus = Fr::MODULUS.to_string();
+    for signal in [modulus.as_str(), "-33", "0x21", ""] {
+        assert!(matches!(
+            convert_snarkjs_groth16(&vk_json, &proof_json, &format!(r#"["{signal}"]"#)),
+            Err(ConvertError::Snarkjs(
+                SnarkjsError::InvalidFieldElement { .. }
+            ))
+        ));
+    }
+
+    // Points must be on the curve, and the key and proof must be BN254 Groth16.
+    let mut proof: serde_json::Value = serde_json::from_str(&proof_json).unwrap();
+    proof["pi_a"][1] = "1".into();
+    assert!(matches!(
+        convert_snarkjs_groth16(&vk_json, &proof.to_string(), &public_json),
+        Err(ConvertError::Snarkjs(SnarkjsError::InvalidPoint(field))) if field == "pi_a"
+    ));
+    let mut vk: serde_json::Value = serde_json::from_str(&vk_json).unwrap();
+    vk["curve"] = "bls12381".into();
+    assert!(matches!(
+        convert_snarkjs_groth16(&vk.to_string(), &proof_json, &public_json),
+        Err(ConvertError::Snarkjs(SnarkjsError::UnsupportedCurve(_)))
+    ));
+    vk["curve"] = "bn128".into();
+    vk["nPublic"] = 2.into();
+    assert!(matches!(
+        convert_snarkjs_groth16(&vk.to_string(), &proof_json, &public_json),
+        Err(ConvertError::Snarkjs(
+            SnarkjsError::PublicInputCountMismatch { n_public: 2, ic: 2 }
+        ))
+    ));
+    assert!(matches!(
+        convert_snarkjs_groth16_files(
+            format!("{dir}/missing.json"),
+            format!("{dir}/proof.json"),
+            format!("{dir}/public.json"),
+        ),
+        Err(ConvertError::Io { .. })
+    ));
+}
+
+#[test]
+fn test_sui_prepared_verifying_key() {
+    use ark_bn254::{G1Affine, G2Affine};
+    use ark_ec::pairing::PairingOutput;
+    use ark_groth16::{PreparedVerifyingKey, Proof, VerifyingKey};
+    use ark_serialize::CanonicalDeserialize;
+
+    use crate::{GROTH16_VK_REGISTRY, SuiPreparedVerifyingKey};
+
+    for (version, groth16_vk, _) in GROTH16_VK_REGISTRY {
+        let pvk = SuiPreparedVerifyingKey::from_groth16_vk(groth16_vk).unwrap();
+        assert_eq!(pvk.vk_gamma_abc_g1.len(), 3 * 32, "{version}");
+        assert_eq!(pvk.alpha_g1_beta_g2.len(), 384, "{version}");
+        assert_eq!(pvk.gamma_g2_neg_pc.len(), 64, "{version}");
+        assert_eq!(pvk.delta_g2_neg_pc.len(), 64, "{version}");
+    }
+
+    let artifacts = fibonacci_artifacts();
+    let pvk = SuiPreparedVerifyingKey::from_sui_vk_bytes(&artifacts.verifying_key).unwrap();
+    assert_eq!(
+        pvk,
+        SuiPreparedVerifyingKey::from_groth16_vk(GROTH16_VK_4_0_0_RC3_BYTES).unwrap()
+    );
+
+    // Rebuild an arkworks prepared key from the components and verify the proof with it.
+    let vk = VerifyingKey::<Bn254>::deserialize_compressed(&*artifacts.verifying_key).unwrap();
+    let gamma_abc_g1 = pvk
+        .vk_gamma_abc_g1
+        .chunks(32)
+        .map(|point| G1Affine::deserialize_compressed(point).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(gamma_abc_g1, vk.gamma_abc_g1);
+    let ark_pvk = PreparedVerifyingKey {
+        vk,
+        alpha_g1_beta_g2: PairingOutput::<Bn254>::deserialize_compressed(&*pvk.alpha_g1_beta_g2)
+            .unwrap()
+            .0,
+        gamma_g2_neg_pc: G2Affine::deserialize_compressed(&*pvk.gamma_g2_neg_pc)
+            .unwrap()
+            .into(),
+        delta_g2_neg_pc: G2Affine::deserialize_compressed(&*pvk.delta_g2_neg_pc)
+            .unwrap()
+            .into(),
+    };
+    let proof = Proof::<Bn254>::deserialize_compressed(&*artifacts.proof_points).unwrap();
+    let public_inputs = artifacts
+        .public_inputs
+        .chunks(32)
+        .map(|input| ark_bn254::Fr::deserialize_compressed(input).unwrap())
+        .collect::<Vec<_>>();
+    assert!(Groth16::<Bn254>::verify_with_processed_vk(&ark_pvk, &public_inputs, &proof).unwrap());
+}
+
+#[test]
+fn test_verify_sui_artifacts() {
+    use crate::{SuiVerifyError, verify_sui_artifacts};
+
+    let artifacts = fibonacci_artifacts();
+    let verification = artifacts.verify().unwrap();
+    assert_eq!(verification.public_inputs.len(), 2);
+    assert_eq!(verification.verifying_key.gamma_abc_g1.len(), 3);
+
+    let vk = &artifacts.verifying_key;
+    let inputs = &artifacts.public_inputs;
+    let proof = &artifacts.proof_points;
+
+    // Inputs for another program vkey hash.
+    let mut other_inputs = inputs.clone();
+    other_inputs[0] ^= 1;
+    assert!(matches!(
+        verify_sui_artifacts(vk, &other_inputs, proof),
+        Err(SuiVerifyError::VerificationFailed)
+    ));
+
+    // Scalars must be canonical.
+    let mut non_canonical_inputs = inputs.clone();
+    non_canonical_inputs[32..].fill(0xFF);
+    assert!(matches!(
+        verify_sui_artifacts(vk, &non_canonical_inputs, proof),
+        Err(SuiVerifyError::InvalidPublicInput { index: 1, .. })
+    ));
+
+    assert!(matches!(
+        verify_sui_artifacts(vk, &inputs[..40], proof),
+        Err(SuiVerifyError::InvalidPublicInputsLength(40))
+    ));
+    assert!(matches!(
+        verify_sui_artifacts(vk, &inputs[..32], proof),
+        Err(SuiVerifyError::PublicInputCountMismatch {
+            expected: 2,
+            actual: 1
+        })
+    ));
+    assert!(matches!(
+        verify_sui_artifacts(vk, &[0; 9 * 32], proof),
+        Err(SuiVerifyError::TooManyPublicInputs(9))
+    ));
+    assert!(matches!(
+        verify_sui_artifacts(vk, inputs, &proof[..64]),
+        Err(SuiVerifyError::InvalidProofPoints(_))
+    ));
+    assert!(matches!(
+        verify_sui_artifacts(&vk[1..], inputs, proof),
+        Err(SuiVerifyError::InvalidVerifyingKey(_))
+    ));
+}
+
+#[test]
+fn test_inspect_sui_artifacts() {
+    use crate::{InspectedCurvePoint, PointStatus, Sp1Version, inspect_sui_artifacts};
+
+    let artifacts = fibonacci_artifacts();
+    let verification = artifacts.verify().unwrap();
+
+    let vk = &artifacts.verifying_key;
+    let inputs = &artifacts.public_inputs;
+    let proof = &artifacts.proof_points;
+    let inspection = inspect_sui_artifacts(vk, inputs, proof);
+    assert!(inspection.is_well_formed(), "{inspection}");
+    assert_eq!(inspection.num_gamma_abc_g1, Some(3));
+    assert_eq!(inspection.expected_public_inputs(), Some(2));
+    assert_eq!(inspection.verifying_key.len(), 7);
+    assert_eq!(
+        inspection.verifying_key[4].point,
+        Some(InspectedCurvePoint::G1(
+            verification.verifying_key.gamma_abc_g1[0]
+        ))
+    );
+    assert_eq!(
+        inspection.proof_points[1].point,
+        Some(InspectedCurvePoint::G2(verification.proof.b))
+    );
+    assert_eq!(inspection.public_inputs[0].hex(), artifacts.vkey_hash_hex());
+    assert_eq!(
+        inspection.public_inputs[1].value.to_string(),
+        BigUint::from_bytes_be(&artifacts.committed_values_digest).to_string()
+    );
+    // The public inputs are named after the detected SP1 version of the verifying key.
+    assert_eq!(inspection.sp1_version, Some(Sp1Version::V4_0_0Rc3));
+    assert!(inspection.to_string().contains("[0] vkey_hash: "));
+    let mut unknown_version = inspection.clone();
+    unknown_version.sp1_version = None;
+    assert!(!unknown_version.to_string().contains("vkey_hash"));
+
+    // Identity and invalid points, non-canonical and missing inputs are flagged.
+    let mut identity_vk = vk.clone();
+    identity_vk[..32].fill(0);
+    identity_vk[31] = 0x40;
+    let mut invalid_proof = proof.clone();
+    invalid_proof[..32].fill(0xFF);
+    invalid_proof[31] = 0x3F;
+    let mut non_canonical_inputs = inputs[..32].to_vec();
+    non_canonical_inputs.fill(0xFF);
+    non_canonical_inputs[31] = 0x3F;
+    let inspection = inspect_sui_artifacts(&identity_vk, &non_canonical_inputs, &invalid_proof);
+    assert_eq!(inspection.verifying_key[0].status, PointStatus::Identity);
+    assert_eq!(inspection.proof_points[0].status, PointStatus::Invalid);
+    assert!(!inspection.public_inputs[0].canonical);
+    assert_eq!(
+        inspection.issues,
+        [
+            "verifying key: alpha_g1 is the point at infinity",
+            "public inputs: input 0 is not smaller than the scalar field modulus",
+            "public inputs: verifying key expects 2 public inputs, got 1",
+            "proof points: a is not a valid point",
+        ]
+    );
+
+    let inspection = inspect_sui_artifacts(&vk[..100], &inputs[..40], &proof[..96]);
+    assert_eq!(
+        inspection.issues,
+        [
+            "verifying key: gamma_g2 at offset 96 is truncated",
+            "public inputs: length 40 is not a multiple of 32",
+            "proof points: c at offset 96 is truncated",
+        ]
+    );
+}
+
+#[cfg(feature = "fastcrypto")]
+#[test]
+fn test_fastcrypto_compatibility() {
+    use crate::{
+        FastcryptoCheckError, SuiPreparedVerifyingKey, fastcrypto_prepare_verifying_key,
+        verify_with_fastcrypto,
+    };
+
+    let artifacts = fibonacci_artifacts();
+
+    // fastcrypto-zkp accepts the artifacts and prepares the same key as the converter.
+    let pvk = artifacts.verify_with_fastcrypto().unwrap();
+    assert_eq!(
+        pvk,
+        SuiPreparedVerifyingKey::from_groth16_vk(GROTH16_VK_4_0_0_RC3_BYTES).unwrap()
+    );
+    assert_eq!(
+        fastcrypto_prepare_verifying_key(&artifacts.verifying_key).unwrap(),
+        pvk
+    );
+
+    let mut public_inputs = artifacts.public_inputs.clone();
+    public_inputs[0] ^= 1;
+    assert!(matches!(
+        verify_with_fastcrypto(
+            &artifacts.verifying_key,
+            &public_inputs,
+            &artifacts.proof_points
+        ),
+        Err(FastcryptoCheckError::VerificationFailed)
+    ));
+    public_inputs[..32].fill(0xFF);
+    assert!(matches!(
+        verify_with_fastcrypto(
+            &artifacts.verifying_key,
+            &public_inputs,
+            &artifacts.proof_points
+        ),
+        Err(FastcryptoCheckError::Verify(_))
+    ));
+    assert!(matches!(
+        verify_with_fastcrypto(
+            &artifacts.verifying_key[1..],
+            &artifacts.public_inputs,
+            &artifacts.proof_points
+        ),
+        Err(FastcryptoCheckError::PrepareVerifyingKey(_))
+    ));
+
+    let mut mock = artifacts.clone();
+    mock.mock = true;
+    assert!(matches!(
+        mock.verify_with_fastcrypto(),
+        Err(FastcryptoCheckError::MockProof)
+    ));
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn test_ffi_convert() {
+    use std::ffi::{CStr, CString};
+    use std::ptr;
+
+    use crate::ffi::{
+        Sp1SuiArtifacts, Sp1SuiBuffer, Sp1SuiStatus, sp1_sui_artifacts_free, sp1_sui_convert,
+        sp1_sui_convert_mock, sp1_sui_last_error_message,
+    };
+
+    fn empty_artifacts() -> Sp1SuiArtifacts {
+        let empty = || Sp1SuiBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        Sp1SuiArtifacts {
+            verifying_key: empty(),
+            public_inputs: empty(),
+            proof_points: empty(),
+        }
+    }
+
+    unsafe fn to_vec(buffer: &Sp1SuiBuffer) -> Vec<u8> {
+        unsafe { std::slice::from_raw_parts(buffer.data, buffer.len) }.to_vec()
+    }
+
+    let public_values = FIBONACCI_PUBLIC_VALUES.to_vec();
+    let proof_bytes = FIBONACCI_PROOF.to_vec();
+    let expected = fibonacci_artifacts();
+    let vkey_hash = CString::new(expected.vkey_hash_hex()).unwrap();
+
+    let mut out = empty_artifacts();
+    let status = unsafe {
+        sp1_sui_convert(
+            proof_bytes.as_ptr(),
+            proof_bytes.len(),
+            public_values.as_ptr(),
+            public_values.len(),
+            vkey_hash.as_ptr(),
+            &mut out,
+        )
+    };
+    assert_eq!(status, Sp1SuiStatus::Ok);
+    assert!(sp1_sui_last_error_message().is_null());
+    unsafe {
+        assert_eq!(to_vec(&out.verifying_key), expected.verifying_key);
+        assert_eq!(to_vec(&out.public_inputs), expected.public_inputs);
+        assert_eq!(to_vec(&out.proof_points), expected.proof_points);
+        sp1_sui_artifacts_free(&mut out);
+        sp1_sui_artifacts_free(&mut out);
+    }
+    assert!(out.verifying_key.data.is_null());
+
+    // Mock proofs have empty proof bytes, and are rejected.
+    let mut out = empty_artifacts();
+    let status = unsafe {
+        sp1_sui_convert(
+            ptr::null(),
+            0,
+            public_values.as_ptr(),
+            public_values.len(),
+            vkey_hash.as_ptr(),
+            &mut out,
+        )
+    };
+    assert_eq!(status, Sp1SuiStatus::MockProof);
+    assert!(out.verifying_key.data.is_null());
+
+    // They convert with the separate mock entry point, which rejects real proofs.
+    let status = unsafe {
+        sp1_sui_convert_mock(
+            ptr::null(),
+            0,
+            public_values.as_ptr(),
+            public_values.len(),
+            vkey_hash.as_ptr(),
+            &mut out,
+        )
+    };
+    assert_eq!(status, Sp1SuiStatus::Ok);
+    unsafe {
+        assert_eq!(out.verifying_key.len, 0);
+        assert_eq!(out.proof_points.len, 0);
+        assert_eq!(to_vec(&out.public_inputs), expected.public_inputs);
+        sp1_sui_artifacts_free(&mut out);
+    }
+    let status = unsafe {
+        sp1_sui_convert_mock(
+            proof_bytes.as_ptr(),
+            proof_bytes.len(),
+            public_values.as_ptr(),
+            public_values.len(),
+            vkey_hash.as_ptr(),
+            &mut out,
+        )
+    };
+    assert_eq!(status, Sp1SuiStatus::NotMockProof);
+    assert!(out.public_inputs.data.is_null());
+
+    #[cfg(feature = "sp1-sdk")]
+    {
+        use crate::ffi::sp1_sui_convert_proof_file;
+
+        let proof_path = CString::new("../proofs/fibonacci_proof.bin").unwrap();
+        let mut out = empty_artifacts();
+        let status = unsafe { sp1_sui_convert_proof_file(proof_path.as_ptr(), &mut out) };
+        assert_eq!(status, Sp1SuiStatus::Ok);
+        unsafe {
+            assert_eq!(to_vec(&out.proof_points), expected.proof_points);
+            sp1_sui_artifacts_free(&mut out);
+        }
+    }
+
+    // Errors are reported with a status code and a message.
+    let mut out = empty_artifacts();
+    let status = unsafe {
+        sp1_sui_convert(
+            proof_bytes.as_ptr(),
+            proof_bytes.len(),
+            ptr::null(),
+            0,
+            vkey_hash.as_ptr(),
+            &mut out,
+        )
+    };
+    assert_eq!(status, Sp1SuiStatus::VerificationFailed);
+    let message = unsafe { CStr::from_ptr(sp1_sui_last_error_message()) };
+    assert_eq!(
+        message.to_str().unwrap(),
+        ConvertError::VerificationFailed.to_string()
+    );
+    assert!(out.verifying_key.data.is_null());
+
+    let status = unsafe {
+        sp1_sui_convert(
+            proof_bytes.as_ptr(),
+            proof_bytes.len(),
+            public_values.as_ptr(),
+            public_values.len(),
+            ptr::null(),
+            &mut out,
+        )
+    };
+    assert_eq!(status, Sp1SuiStatus::InvalidArgument);
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn test_ffi_header() {
+    // The committed header is the one generated by the build script.
+    assert!(
+        include_str!("../include/sp1_sui.h")
+            == include_str!(concat!(env!("OUT_DIR"), "/sp1_sui.h")),
+        "include/sp1_sui.h is out of date, regenerate it with \
+         `cbindgen --config cbindgen.toml --output include/sp1_sui.h`"
+    );
+}
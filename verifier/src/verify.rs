@@ -0,0 +1,123 @@
+use ark_bn254::{Bn254, Fr};
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use ark_groth16::{Groth16, Proof, VerifyingKey, prepare_verifying_key};
+use ark_serialize::{CanonicalDeserialize, SerializationError};
+use ark_snark::SNARK;
+use thiserror::Error;
+
+/// Maximum number of public inputs accepted by `sui::groth16::public_proof_inputs_from_bytes`.
+pub const SUI_MAX_PUBLIC_INPUTS: usize = 8;
+
+/// Errors returned when verifying Sui Groth16 artifacts.
+///
+/// Every variant except [`SuiVerifyError::VerificationFailed`] corresponds to an abort of the
+/// `sui::groth16` functions; a failed verification returns `false` on-chain.
+#[derive(Error, Debug)]
+pub enum SuiVerifyError {
+    #[error("Invalid verifying key: {0}")]
+    InvalidVerifyingKey(#[source] SerializationError),
+    #[error("Invalid public inputs: length {0} is not a multiple of 32")]
+    InvalidPublicInputsLength(usize),
+    #[error("Too many public inputs: {0}, at most {SUI_MAX_PUBLIC_INPUTS} are supported")]
+    TooManyPublicInputs(usize),
+    #[error("Invalid public input {index}: {source}")]
+    InvalidPublicInput {
+        index: usize,
+        #[source]
+        source: SerializationError,
+    },
+    #[error("Invalid proof points: {0}")]
+    InvalidProofPoints(#[source] SerializationError),
+    #[error("Verifying key expects {expected} public inputs, got {actual}")]
+    PublicInputCountMismatch { expected: usize, actual: usize },
+    #[error("Mock artifacts have no verifying key or proof points to verify")]
+    MockProof,
+    #[error("Groth16 proof failed verification")]
+    VerificationFailed,
+}
+
+/// The artifacts parsed by [`verify_sui_artifacts`], over BN254 unless stated otherwise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuiVerification<E: Pairing = Bn254> {
+    pub verifying_key: VerifyingKey<E>,
+    pub public_inputs: Vec<E::ScalarField>,
+    pub proof: Proof<E>,
+}
+
+/// Parse the public inputs the way `sui::groth16::public_proof_inputs_from_bytes` does: each
+/// input is a canonical 32 byte little endian scalar.
+pub fn parse_sui_public_inputs(inputs_bytes: &[u8]) -> Result<Vec<Fr>, SuiVerifyError> {
+    parse_sui_scalars(inputs_bytes)
+}
+
+/// [`parse_sui_public_inputs`] over any 32 byte scalar field.
+fn parse_sui_scalars<F: PrimeField>(inputs_bytes: &[u8]) -> Result<Vec<F>, SuiVerifyError> {
+    if !inputs_bytes.len().is_multiple_of(32) {
+        return Err(SuiVerifyError::InvalidPublicInputsLength(
+            inputs_bytes.len(),
+        ));
+    }
+    let num_inputs = inputs_bytes.len() / 32;
+    if num_inputs > SUI_MAX_PUBLIC_INPUTS {
+        return Err(SuiVerifyError::TooManyPublicInputs(num_inputs));
+    }
+    inputs_bytes
+        .chunks_exact(32)
+        .enumerate()
+        .map(|(index, input)| {
+            F::deserialize_compressed(input)
+                .map_err(|source| SuiVerifyError::InvalidPublicInput { index, source })
+        })
+        .collect()
+}
+
+/// Verify the three byte arrays passed to `verify_groth16_bn254_proof` off-chain.
+///
+/// The compressed verifying key, public inputs and proof points are parsed and validated as
+/// `sui::groth16` does, and the proof is verified with arkworks. Unlike the check performed by
+/// the converter, this verifies the serialized bytes that are sent on-chain.
+pub fn verify_sui_artifacts(
+    vk_bytes: &[u8],
+    inputs_bytes: &[u8],
+    proof_bytes: &[u8],
+) -> Result<SuiVerification, SuiVerifyError> {
+    verify_sui_groth16::<Bn254>(vk_bytes, inputs_bytes, proof_bytes)
+}
+
+/// Verify the three byte arrays passed to `groth16::verify_groth16_proof` off-chain, for the
+/// curve `E` of `groth16::bn254()` or `groth16::bls12381()`.
+///
+/// See [`verify_sui_artifacts`].
+pub fn verify_sui_groth16<E: Pairing>(
+    vk_bytes: &[u8],
+    inputs_bytes: &[u8],
+    proof_bytes: &[u8],
+) -> Result<SuiVerification<E>, SuiVerifyError> {
+    let verifying_key = VerifyingKey::<E>::deserialize_compressed(vk_bytes)
+        .map_err(SuiVerifyError::InvalidVerifyingKey)?;
+    let public_inputs = parse_sui_scalars(inputs_bytes)?;
+    let proof = Proof::<E>::deserialize_compressed(proof_bytes)
+        .map_err(SuiVerifyError::InvalidProofPoints)?;
+
+    let expected = verifying_key.gamma_abc_g1.len().saturating_sub(1);
+    if public_inputs.len() != expected {
+        return Err(SuiVerifyError::PublicInputCountMismatch {
+            expected,
+            actual: public_inputs.len(),
+        });
+    }
+
+    let pvk = prepare_verifying_key(&verifying_key);
+    let verified = Groth16::<E>::verify_with_processed_vk(&pvk, &public_inputs, &proof)
+        .map_err(|_| SuiVerifyError::VerificationFailed)?;
+    if !verified {
+        return Err(SuiVerifyError::VerificationFailed);
+    }
+
+    Ok(SuiVerification {
+        verifying_key,
+        public_inputs,
+        proof,
+    })
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+include!(concat!(env!("OUT_DIR"), "/sp1_versions.rs"));
+
+/// Error returned when parsing an unknown SP1 version name.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Unknown SP1 version {0}, supported versions are {supported}", supported = supported_versions_list())]
+pub struct ParseSp1VersionError(pub String);
+
+/// Every SP1 version with an embedded Groth16 verifying key, newest first.
+pub fn supported_versions() -> &'static [Sp1Version] {
+    SUPPORTED_VERSIONS
+}
+
+fn supported_versions_list() -> String {
+    SUPPORTED_VERSIONS
+        .iter()
+        .map(|version| version.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Sp1Version {
+    /// The newest supported SP1 version.
+    pub fn latest() -> Self {
+        SUPPORTED_VERSIONS[0]
+    }
+
+    /// The version whose Groth16 verifying key matches the 4 byte prefix of SP1 proof bytes.
+    pub fn from_groth16_vk_hash_prefix(prefix: [u8; 4]) -> Option<Self> {
+        GROTH16_VK_REGISTRY
+            .iter()
+            .find(|(_, _, vk_prefix)| *vk_prefix == prefix)
+            .map(|(version, _, _)| *version)
+    }
+
+    /// The version whose Groth16 verifying key is exactly `groth16_vk`.
+    pub fn from_groth16_vk(groth16_vk: &[u8]) -> Option<Self> {
+        GROTH16_VK_REGISTRY
+            .iter()
+            .find(|(_, vk, _)| *vk == groth16_vk)
+            .map(|(version, _, _)| *version)
+    }
+}
+
+impl fmt::Display for Sp1Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Sp1Version {
+    type Err = ParseSp1VersionError;
+
+    /// Parse a version name such as `v4.0.0-rc.3`. The `v` prefix is optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.strip_prefix('v').unwrap_or(s);
+        SUPPORTED_VERSIONS
+            .iter()
+            .copied()
+            .find(|version| {
+                let version = version.as_str();
+                version.strip_prefix('v').unwrap_or(version) == name
+            })
+            .ok_or_else(|| ParseSp1VersionError(s.to_string()))
+    }
+}
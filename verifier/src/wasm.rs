@@ -0,0 +1,85 @@
+//! WebAssembly bindings for converting SP1 Groth16 proofs in the browser or Node.
+//!
+//! Build the `sp1-sui-bindings` crate with `wasm-pack build --out-name sp1_sui -- --features wasm`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{SuiGroth16Artifacts, convert_mock, convert_raw_with_embedded_vk};
+
+/// The Sui verifier arguments of a converted SP1 Groth16 proof.
+#[wasm_bindgen(js_name = SuiGroth16Artifacts)]
+pub struct WasmSuiGroth16Artifacts(SuiGroth16Artifacts);
+
+#[wasm_bindgen(js_class = SuiGroth16Artifacts)]
+impl WasmSuiGroth16Artifacts {
+    /// Compressed arkworks Groth16 verifying key.
+    #[wasm_bindgen(getter, js_name = verifyingKey)]
+    pub fn verifying_key(&self) -> Vec<u8> {
+        self.0.verifying_key.clone()
+    }
+
+    /// Concatenated 32 byte little endian public inputs.
+    #[wasm_bindgen(getter, js_name = publicInputs)]
+    pub fn public_inputs(&self) -> Vec<u8> {
+        self.0.public_inputs.clone()
+    }
+
+    /// Compressed arkworks Groth16 proof points.
+    #[wasm_bindgen(getter, js_name = proofPoints)]
+    pub fn proof_points(&self) -> Vec<u8> {
+        self.0.proof_points.clone()
+    }
+
+    /// SP1 version of the Groth16 verifying key used for the conversion.
+    #[wasm_bindgen(getter, js_name = sp1Version)]
+    pub fn sp1_version(&self) -> String {
+        self.0.sp1_version.clone()
+    }
+
+    /// Program vkey hash as a `0x` prefixed hex string.
+    #[wasm_bindgen(getter, js_name = vkeyHash)]
+    pub fn vkey_hash(&self) -> String {
+        self.0.vkey_hash_hex()
+    }
+
+    /// Whether the artifacts were converted from a mock proof, and carry no pairing data.
+    #[wasm_bindgen(getter)]
+    pub fn mock(&self) -> bool {
+        self.0.mock
+    }
+}
+
+impl WasmSuiGroth16Artifacts {
+    /// The converted artifacts.
+    pub fn into_inner(self) -> SuiGroth16Artifacts {
+        self.0
+    }
+}
+
+/// Convert SP1 Groth16 proof bytes, as returned by `SP1ProofWithPublicValues::bytes`, to the
+/// arguments of the Sui Groth16 verifier.
+///
+/// The Groth16 verifying key is selected from the embedded keys using the prefix of the proof.
+/// `vkey_hash` is the program vkey hash as returned by `SP1VerifyingKey::bytes32`. Proof bytes of
+/// the mock prover are rejected; see `convertSp1MockProof`.
+#[wasm_bindgen(js_name = convertSp1Proof)]
+pub fn convert_sp1_proof(
+    proof_bytes: &[u8],
+    public_values: &[u8],
+    vkey_hash: &str,
+) -> Result<WasmSuiGroth16Artifacts, JsError> {
+    let artifacts = convert_raw_with_embedded_vk(proof_bytes, public_values, vkey_hash)?;
+    Ok(WasmSuiGroth16Artifacts(artifacts))
+}
+
+/// Convert the empty proof bytes of the SP1 mock prover to mock artifacts, which carry the public
+/// inputs but no verifying key or proof points.
+#[wasm_bindgen(js_name = convertSp1MockProof)]
+pub fn convert_sp1_mock_proof(
+    proof_bytes: &[u8],
+    public_values: &[u8],
+    vkey_hash: &str,
+) -> Result<WasmSuiGroth16Artifacts, JsError> {
+    let artifacts = convert_mock(proof_bytes, public_values, vkey_hash)?;
+    Ok(WasmSuiGroth16Artifacts(artifacts))
+}
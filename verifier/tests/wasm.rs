@@ -0,0 +1,52 @@
+//! Tests of the WebAssembly bindings. Run with `wasm-bindgen-test-runner` as the target runner:
+//! `cargo test --target wasm32-unknown-unknown --no-default-features --features wasm`.
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use hex_literal::hex;
+use sp1_sui::wasm::{convert_sp1_mock_proof, convert_sp1_proof};
+use sp1_sui::{GROTH16_VK_4_0_0_RC3_BYTES, convert_raw};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// `SP1ProofWithPublicValues::bytes` of `proofs/fibonacci_proof.bin`.
+const PROOF_BYTES: [u8; 260] = hex!(
+    "11b6a09d027885899a8a8060cb07f0b65a2acac245bacfa9f11cf06e540d5b672d9a0151164c66c0ee0ca9541bd2b06e6edc4dcf9e83cc16223511c9727b08b1e5ed7d8c0afc08213c56f7fbb816de6da5bed36ca2abd71db76e253264cebefbe1e88b2013e9893a7f993b07310da1af3bfb6b38fbac6322abc1b997d9d64ba41aab47cd15c3e767ece8954ededb0cd9684dea06f111d3803c062ea20d7c728d8829f67e0561205ce9f1f16ba809e94e39c5f3b04c76340cf7f73cdd2bed97efd4bb744f1d01f03f7fa0595da5250985b0ebfaf868745c0f463c990be40f3ff8c77edd9007bf70cd5c37b7568a6a1f3a554bb9d9b86912b55ad18198ab274bddde47e1e8"
+);
+/// Public values of `proofs/fibonacci_proof.bin`.
+const PUBLIC_VALUES: [u8; 96] = hex!(
+    "00000000000000000000000000000000000000000000000000000000000000140000000000000000000000000000000000000000000000000000000000001a6d0000000000000000000000000000000000000000000000000000000000002ac2"
+);
+const VKEY_HASH: &str = "0x00643545b9a0436e71ec17b98049f8f75ca54f277b8e8b89e4c641725c90ff81";
+
+#[wasm_bindgen_test]
+fn test_convert_sp1_proof() {
+    let artifacts = convert_sp1_proof(&PROOF_BYTES, &PUBLIC_VALUES, VKEY_HASH).unwrap();
+    assert_eq!(artifacts.sp1_version(), "v4.0.0-rc.3");
+    assert_eq!(artifacts.vkey_hash(), VKEY_HASH);
+
+    let expected = convert_raw(
+        &PROOF_BYTES,
+        &PUBLIC_VALUES,
+        VKEY_HASH,
+        GROTH16_VK_4_0_0_RC3_BYTES,
+    )
+    .unwrap();
+    assert_eq!(artifacts.verifying_key(), expected.verifying_key);
+    assert_eq!(artifacts.public_inputs(), expected.public_inputs);
+    assert_eq!(artifacts.proof_points(), expected.proof_points);
+}
+
+#[wasm_bindgen_test]
+fn test_convert_sp1_proof_errors() {
+    assert!(convert_sp1_proof(&PROOF_BYTES, &[1, 2, 3], VKEY_HASH).is_err());
+    assert!(convert_sp1_proof(&PROOF_BYTES, &PUBLIC_VALUES, "0x1234").is_err());
+    assert!(convert_sp1_proof(&PROOF_BYTES[..2], &PUBLIC_VALUES, VKEY_HASH).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_convert_sp1_mock_proof() {
+    assert!(convert_sp1_proof(&[], &PUBLIC_VALUES, VKEY_HASH).is_err());
+    let artifacts = convert_sp1_mock_proof(&[], &PUBLIC_VALUES, VKEY_HASH).unwrap();
+    assert!(artifacts.mock());
+    assert!(artifacts.verifying_key().is_empty());
+    assert!(convert_sp1_mock_proof(&PROOF_BYTES, &PUBLIC_VALUES, VKEY_HASH).is_err());
+}